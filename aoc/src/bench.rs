@@ -0,0 +1,111 @@
+use std::{path::PathBuf, time::Duration};
+
+use aoc_core::bench::{self, Baseline};
+use clap::Args;
+
+use crate::{read_input, select_days, InputArgs};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day to benchmark, defaults to all days
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Number of runs per day
+    #[arg(short = 'n', long, default_value_t = 10)]
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Compares the median timings against a baseline saved earlier
+    #[arg(short, long)]
+    baseline: Option<PathBuf>,
+    /// Saves the median timings as a new baseline
+    #[arg(short, long)]
+    save_baseline: Option<PathBuf>,
+    /// Slowdown compared to the baseline, in percent, flagged as regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Benchmarks the selected days, returns false if a day failed or regressed
+pub fn bench(args: &BenchArgs) -> bool {
+    let selection = match select_days(args.day) {
+        Some(selection) => selection,
+        None => return false,
+    };
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("Error while loading baseline: {}", err);
+                return false;
+            }
+        },
+        None => None,
+    };
+
+    let source = args.input.input_source();
+    let mut measured = Baseline::default();
+    let mut success = true;
+
+    for day in selection {
+        let input = match read_input(&source, day) {
+            Some(input) => input,
+            None => {
+                success = false;
+                continue;
+            }
+        };
+
+        let stats = match bench::measure(day.puzzle, &input, args.iterations as usize) {
+            Ok(stats) => stats,
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day.number(), err);
+                success = false;
+                continue;
+            }
+        };
+
+        for (stage, stats) in stats {
+            measured.insert(day.number(), stage, stats.median);
+
+            let comparison = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(day.number(), stage))
+                .map(|previous| {
+                    let change = bench::relative_change(stats.median, previous) * 100.0;
+                    if change > args.threshold {
+                        success = false;
+                        format!("  {:+.1}% regression", change)
+                    } else {
+                        format!("  {:+.1}%", change)
+                    }
+                })
+                .unwrap_or_default();
+
+            println!(
+                "Day {:02} {:<6} min {:>10}  median {:>10}  max {:>10}{}",
+                day.number(),
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+                comparison
+            );
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        if let Err(err) = measured.save(path) {
+            eprintln!("Error while saving baseline: {}", err);
+            success = false;
+        }
+    }
+
+    success
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
@@ -0,0 +1,86 @@
+use std::{fs, path::PathBuf};
+
+use aoc_core::examples;
+use clap::Args;
+
+#[derive(Args)]
+pub struct ExamplesArgs {
+    /// Puzzle page as saved from the browser
+    page: PathBuf,
+    /// Day of the puzzle, defaults to the day named in the title of the page
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Directory to write the fixtures to, one subdirectory per day
+    #[arg(long, default_value = examples::DEFAULT_EXAMPLES_DIR)]
+    examples_dir: PathBuf,
+}
+
+/// Extracts the examples of a puzzle page into the day's fixtures, returns false if that failed
+pub fn examples(args: &ExamplesArgs) -> bool {
+    let html = match fs::read_to_string(&args.page) {
+        Ok(html) => html,
+        Err(err) => {
+            eprintln!("Error while reading {}: {}", args.page.display(), err);
+            return false;
+        }
+    };
+
+    let page = examples::extract(&html);
+    let day = match args.day.or(page.day) {
+        Some(day) => day,
+        None => {
+            eprintln!("The page names no day, select it with --day");
+            return false;
+        }
+    };
+    if page.examples.is_empty() {
+        eprintln!("No examples found in {}", args.page.display());
+        return false;
+    }
+
+    // Parameters are not part of the page, those set by hand for an example are kept
+    let previous = match examples::load(&args.examples_dir, day) {
+        Ok(previous) => previous,
+        Err(err) => {
+            eprintln!("Error while loading examples: {}", err);
+            return false;
+        }
+    };
+    let mut extracted = page.examples;
+    for example in &mut extracted {
+        if let Some(known) = previous.iter().find(|known| known.input == example.input) {
+            example.params = known.params.clone();
+        }
+    }
+
+    if let Err(err) = examples::save(&args.examples_dir, day, &extracted) {
+        eprintln!("Error while saving examples: {}", err);
+        return false;
+    }
+
+    for (number, example) in (1..).zip(&extracted) {
+        let answers = example
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("part {} = {}", part, answer))
+            .collect::<Vec<_>>();
+        println!(
+            "Day {:02}, example {}: {} lines, {}",
+            day,
+            number,
+            example.input.lines().count(),
+            if answers.is_empty() {
+                "no answers".to_string()
+            } else {
+                answers.join(", ")
+            }
+        );
+    }
+    println!(
+        "Wrote {} examples to {}",
+        extracted.len(),
+        examples::day_dir(&args.examples_dir, day).display()
+    );
+
+    true
+}
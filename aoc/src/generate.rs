@@ -0,0 +1,96 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{input, verify::Answers};
+use clap::{ArgGroup, Args};
+
+use crate::select_days;
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["day", "output_dir"])))]
+pub struct GenerateArgs {
+    /// Day to generate an input for, defaults to all days if an output directory is given
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Seed of the generator, defaults to a random one which is printed to stderr
+    #[arg(short, long)]
+    seed: Option<u64>,
+    /// Directory to write one `day_XX.txt` file per day to, instead of printing the input
+    #[arg(short, long)]
+    output_dir: Option<PathBuf>,
+    /// Answers file to record the answers known from generating the inputs in, dropping the
+    /// recorded answers of parts whose answer is not known
+    #[arg(long, requires = "output_dir")]
+    answers: Option<PathBuf>,
+}
+
+/// Generates inputs for the selected days, returns false if they could not be written
+pub fn generate(args: &GenerateArgs) -> bool {
+    let selection = match select_days(args.day) {
+        Some(selection) => selection,
+        None => return false,
+    };
+
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+        eprintln!("Seed: {}", seed);
+        seed
+    });
+
+    let dir = match &args.output_dir {
+        Some(dir) => dir,
+        None => {
+            for day in selection {
+                println!("{}", day.puzzle.generate(seed).input);
+            }
+            return true;
+        }
+    };
+
+    if let Err(err) = fs::create_dir_all(dir) {
+        eprintln!("Error while creating {}: {}", dir.display(), err);
+        return false;
+    }
+
+    let mut answers = match args.answers.as_deref().map(Answers::load).transpose() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Error while loading answers: {}", err);
+            return false;
+        }
+    };
+
+    for day in selection {
+        let generated = day.puzzle.generate(seed);
+        let path = input::input_path(dir, day.number());
+        if let Err(err) = fs::write(&path, format!("{}\n", generated.input)) {
+            eprintln!("Error while writing {}: {}", path.display(), err);
+            return false;
+        }
+
+        if let Some(answers) = &mut answers {
+            for part in day.parts() {
+                match generated.answer(part) {
+                    Some(answer) => answers.insert(day.number(), part, answer),
+                    None => {
+                        answers.remove(day.number(), part);
+                    }
+                }
+            }
+        }
+    }
+
+    if let (Some(answers), Some(path)) = (answers, &args.answers) {
+        if let Err(err) = answers.save(path) {
+            eprintln!("Error while saving answers: {}", err);
+            return false;
+        }
+    }
+
+    true
+}
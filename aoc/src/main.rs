@@ -0,0 +1,435 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    input::{self, InputSource},
+    trace::{self, Trace},
+    Error,
+};
+use aoc_days::{Day, DAYS};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+
+mod bench;
+mod examples;
+mod generate;
+mod repl;
+mod runner;
+mod verify;
+mod visualise;
+
+use runner::{Job, Outcome};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solvers of a single day or of all days in parallel
+    Run(RunArgs),
+    /// Times parsing and each part of a single day or of all days
+    Bench(bench::BenchArgs),
+    /// Checks the answers of a single day or of all days against the known answers
+    Verify(verify::VerifyArgs),
+    /// Generates random inputs, recording the answers known from generating them
+    Generate(generate::GenerateArgs),
+    /// Shows how a day is solved as an animation in the terminal or exports it as PNG or GIF
+    Visualise(visualise::VisualiseArgs),
+    /// Loads a day's input and explores its data structures interactively
+    Repl(repl::ReplArgs),
+    /// Extracts the examples of a saved puzzle page into fixtures which the tests run
+    Examples(examples::ExamplesArgs),
+    /// Lists all available days
+    List,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Part to run, defaults to all parts of the selected day
+    #[arg(short, long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Runs all parts of all days
+    #[arg(short, long)]
+    all: bool,
+    /// Output format of the answers
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Number of parts solved at the same time, defaults to the number of CPUs
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Seconds each day may take for all of its parts together, parts not finished by then are
+    /// given up on
+    #[arg(short, long, default_value_t = 300, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: u64,
+    /// Reports the counters and spans recorded while solving each part, requires building with
+    /// `--features trace`
+    #[arg(long)]
+    trace: bool,
+    /// Writes the spans recorded while solving to a file in the folded stack format of
+    /// flamegraph tools, requires building with `--features trace`
+    #[arg(long)]
+    flamegraph: Option<PathBuf>,
+    /// Reads the input line by line while solving instead of all at once up front, so that days
+    /// 1, 2, 5, 8, 9, 10, 12, 14, 18 and 24 only keep what they need of it in memory
+    #[arg(long)]
+    stream: bool,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// A table with one human-readable line per part
+    Text,
+    /// One JSON object per part and line, including timings and intermediate results
+    Json,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Input file of the selected day, `-` reads from stdin
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Directory containing one `day_XX.txt` input file per day
+    #[arg(long, env = input::INPUT_DIR_VAR, default_value = input::DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+    /// Reads the inputs of this user, which are stored in a subdirectory of the input directory
+    /// named after them. `--input` takes precedence
+    #[arg(short, long, env = input::USER_VAR)]
+    user: Option<String>,
+}
+
+impl InputArgs {
+    fn input_source(&self) -> InputSource {
+        match (&self.input, &self.user) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(path.clone()),
+            (None, Some(user)) => InputSource::Directory(input::user_dir(&self.input_dir, user)),
+            (None, None) => InputSource::Directory(self.input_dir.clone()),
+        }
+    }
+
+    /// File of the answers known for the inputs, which a user keeps next to their inputs
+    fn answers_path(&self) -> PathBuf {
+        match &self.user {
+            Some(user) => input::answers_path(&input::user_dir(&self.input_dir, user)),
+            None => input::answers_path(&self.input_dir),
+        }
+    }
+}
+
+#[derive(Args)]
+struct ParamArgs {
+    /// Sets a parameter of the puzzle, such as `--param window=5` for day 9, may be repeated.
+    /// Each day only receives its own parameters, `list` shows all of them
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+    params: Vec<(String, String)>,
+}
+
+impl ParamArgs {
+    /// The parameters declared by `day`
+    fn for_day(&self, day: &Day) -> Vec<(String, String)> {
+        let declared = day.puzzle.params();
+        self.params
+            .iter()
+            .filter(|(name, _)| declared.iter().any(|param| param.name == name))
+            .cloned()
+            .collect()
+    }
+
+    /// Checks that every parameter is declared by one of the selected days
+    fn check(&self, selection: &[&Day]) -> bool {
+        let mut known = true;
+        for (name, _) in &self.params {
+            if !selection
+                .iter()
+                .any(|day| day.puzzle.params().iter().any(|param| param.name == name))
+            {
+                eprintln!("No selected day has a parameter {:?}", name);
+                known = false;
+            }
+        }
+        known
+    }
+}
+
+fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+    match assignment.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_string(), value.to_string())),
+        None => Err(format!("expected NAME=VALUE, found {:?}", assignment)),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let success = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Verify(args) => verify::verify(&args),
+        Command::Generate(args) => generate::generate(&args),
+        Command::Visualise(args) => visualise::visualise(&args),
+        Command::Repl(args) => repl::repl(&args),
+        Command::Examples(args) => examples::examples(&args),
+        Command::List => {
+            list();
+            true
+        }
+    };
+
+    if !success {
+        std::process::exit(1);
+    }
+}
+
+/// The selected day or all days if none is selected
+fn select_days(day: Option<u8>) -> Option<Vec<&'static Day>> {
+    match day {
+        Some(number) => match aoc_days::find(number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("Day {} is not available", number);
+                None
+            }
+        },
+        None => Some(DAYS.iter().collect()),
+    }
+}
+
+fn read_input(source: &InputSource, day: &Day) -> Option<String> {
+    match source.read(day.number()) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!(
+                "Day {:02}: error while reading input: {}",
+                day.number(),
+                err
+            );
+            None
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> bool {
+    let selection = match select_days(args.day) {
+        Some(selection) => selection,
+        None => return false,
+    };
+
+    if !args.params.check(&selection) {
+        return false;
+    }
+
+    let traced = args.trace || args.flamegraph.is_some();
+    if traced && !trace::ENABLED {
+        eprintln!("Tracing requires building with `--features trace`");
+        return false;
+    }
+
+    let source = args.input.input_source();
+    let mut success = true;
+    let mut tasks = Vec::new();
+    let mut jobs: Vec<(u8, Job<_>)> = Vec::new();
+
+    for day in selection {
+        let parts = match args.part {
+            Some(part) if day.parts().contains(&part) => vec![part],
+            Some(part) => {
+                eprintln!("Day {:02} has no part {}", day.number(), part);
+                return false;
+            }
+            None => day.parts(),
+        };
+
+        // Streamed inputs are opened by each part, stdin can only be read once though
+        let input: Option<Arc<str>> = if args.stream {
+            if source == InputSource::Stdin && parts.len() > 1 {
+                eprintln!("Streaming from stdin requires selecting a single part with --part");
+                return false;
+            }
+            None
+        } else {
+            match read_input(&source, day) {
+                Some(input) => Some(input.into()),
+                None => {
+                    success = false;
+                    continue;
+                }
+            }
+        };
+
+        let params = Arc::new(args.params.for_day(day));
+        for part in parts {
+            let input = input.clone();
+            let source = source.clone();
+            let params = Arc::clone(&params);
+            tasks.push((day.number(), part));
+            jobs.push((
+                day.number(),
+                Box::new(move || {
+                    let solve = || match &input {
+                        Some(input) => day.solve_with(part, input, &params),
+                        None => source
+                            .open(day.number())
+                            .map_err(Error::from)
+                            .and_then(|mut reader| day.stream_with(part, &mut reader, &params)),
+                    };
+                    if traced {
+                        trace::collect(solve)
+                    } else {
+                        (solve(), Trace::default())
+                    }
+                }),
+            ));
+        }
+    }
+
+    let workers = args.jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, |workers| workers.get()),
+        |jobs| jobs as usize,
+    );
+    let timeout = Duration::from_secs(args.timeout);
+    let start = Instant::now();
+    let completed = runner::execute(jobs, workers, Some(timeout));
+    let elapsed = start.elapsed();
+
+    if args.format == Format::Text {
+        println!("Day  Part  {:>10}  Answer", "Time");
+    }
+
+    let (mut solved, mut failed, mut timed_out) = (0, 0, 0);
+    let mut traces = Vec::new();
+    for ((day, part), job) in tasks.into_iter().zip(completed) {
+        let (result, trace) = match job.outcome {
+            Outcome::Finished((Ok(report), trace)) => {
+                solved += 1;
+                (Ok(report), trace)
+            }
+            Outcome::Finished((Err(err), trace)) => {
+                failed += 1;
+                (Err(err.to_string()), trace)
+            }
+            Outcome::Panicked(message) => {
+                failed += 1;
+                (Err(format!("panicked: {}", message)), Trace::default())
+            }
+            Outcome::TimedOut => {
+                timed_out += 1;
+                let err = format!("timed out, the day took over {} s", args.timeout);
+                (Err(err), Trace::default())
+            }
+        };
+        success &= result.is_ok();
+
+        match (args.format, result) {
+            (Format::Text, result) => println!(
+                "{:02}   {:>4}  {:>10}  {}",
+                day,
+                part,
+                bench::format_duration(job.duration),
+                result.map_or_else(|err| err, |report| report.answer)
+            ),
+            (Format::Json, result) => {
+                let mut object = match result {
+                    Ok(report) => json!({
+                        "day": day,
+                        "part": part,
+                        "answer": report.answer,
+                        "duration_ns": job.duration.as_nanos() as u64,
+                        "details": report.details,
+                    }),
+                    Err(err) => json!({ "day": day, "part": part, "error": err }),
+                };
+                if args.trace {
+                    object["trace"] = trace_json(&trace);
+                }
+                println!("{}", object);
+            }
+        }
+
+        traces.push((day, part, trace));
+    }
+
+    if args.format == Format::Text {
+        println!(
+            "{} solved, {} failed, {} timed out in {}",
+            solved,
+            failed,
+            timed_out,
+            bench::format_duration(elapsed)
+        );
+
+        if args.trace {
+            for (day, part, trace) in traces.iter().filter(|(_, _, trace)| !trace.is_empty()) {
+                print!("\nDay {:02}, part {}\n{}", day, part, trace);
+            }
+        }
+    }
+
+    if let Some(path) = &args.flamegraph {
+        // Stacks of all days are put below a frame of their day
+        let mut folded = String::new();
+        for (day, _, trace) in &traces {
+            for line in trace.folded().lines() {
+                folded.push_str(&format!("day {:02};{}\n", day, line));
+            }
+        }
+
+        if let Err(err) = fs::write(path, folded) {
+            eprintln!("Error while writing {}: {}", path.display(), err);
+            return false;
+        }
+    }
+
+    success
+}
+
+fn trace_json(trace: &Trace) -> serde_json::Value {
+    let spans = trace
+        .spans
+        .iter()
+        .map(|(path, stats)| {
+            json!({
+                "path": path,
+                "calls": stats.calls,
+                "total_ns": stats.total.as_nanos() as u64,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({ "counters": trace.counters, "spans": spans })
+}
+
+fn list() {
+    for day in DAYS {
+        println!(
+            "Day {:02}: {} ({} part{})",
+            day.number(),
+            day.puzzle.title(),
+            day.parts().len(),
+            if day.parts().len() == 1 { "" } else { "s" }
+        );
+        for param in day.puzzle.params() {
+            println!(
+                "    {:<28} {}",
+                format!("{}={}", param.name, param.default),
+                param.description
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::io::{self, BufRead, Write};
+
+use aoc_core::repl::{Command, Session};
+use clap::Args;
+
+use crate::{read_input, select_days, InputArgs};
+
+#[derive(Args)]
+pub struct ReplArgs {
+    /// Day to explore, days 7, 8, 15 and 20 support this
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Explores the parsed input of the selected day with commands read from stdin, returns false if
+/// that failed
+pub fn repl(args: &ReplArgs) -> bool {
+    let day = match select_days(Some(args.day)) {
+        Some(selection) => selection[0],
+        None => return false,
+    };
+    let input = match read_input(&args.input.input_source(), day) {
+        Some(input) => input,
+        None => return false,
+    };
+
+    let (stdin, stdout) = (io::stdin(), io::stdout());
+    let mut result = Ok(());
+    let explored = day.puzzle.explore(&input, &mut |session| {
+        result = run(day.number(), session, &mut stdin.lock(), &mut stdout.lock());
+    });
+
+    match (explored, result) {
+        (Ok(()), Ok(())) => true,
+        (Err(err), _) => {
+            eprintln!("Day {:02}: {}", day.number(), err);
+            false
+        }
+        (_, Err(err)) => {
+            eprintln!("Error while reading commands: {}", err);
+            false
+        }
+    }
+}
+
+/// Commands of the REPL itself, which every session understands
+const BUILTIN: &[Command] = &[
+    Command {
+        name: "help",
+        args: "",
+        help: "Lists the commands",
+    },
+    Command {
+        name: "quit",
+        args: "",
+        help: "Leaves the REPL",
+    },
+];
+
+/// Reads commands line by line until `quit` or the end of the input, printing what they return
+fn run(
+    day: u8,
+    session: &mut dyn Session,
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(
+        writer,
+        "Type `help` to list the commands, `quit` or end the input to leave"
+    )?;
+
+    loop {
+        write!(writer, "day {:02}> ", day)?;
+        writer.flush()?;
+
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            writeln!(writer)?;
+            return Ok(());
+        }
+
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        match words.split_first() {
+            None => {}
+            Some((&"quit", _)) | Some((&"exit", _)) => return Ok(()),
+            Some((&"help", _)) => {
+                for command in session.commands().iter().chain(BUILTIN) {
+                    let usage = format!("{} {}", command.name, command.args);
+                    writeln!(writer, "  {:<28} {}", usage, command.help)?;
+                }
+            }
+            Some((command, args)) => match session.execute(command, args) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => writeln!(writer, "{}", output)?,
+                Err(err) => writeln!(writer, "error: {}", err)?,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod repl_test {
+    use crate::repl::run;
+
+    fn session(day: u8, input: &str, commands: &str) -> String {
+        let mut output = Vec::new();
+        aoc_days::find(day)
+            .unwrap()
+            .puzzle
+            .explore(input, &mut |session| {
+                run(day, session, &mut commands.as_bytes(), &mut output).unwrap()
+            })
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_run() {
+        let output = session(8, "acc +3\njmp -1", "step\n\nflip 0\nrun\nquit\nstate\n");
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[1..],
+            [
+                "day 08>    0: acc +3     acc 3",
+                "ip 1, acc 3, 1 instruction executed",
+                "day 08> day 08> error: invalid parameter: line 0 is an acc, only jmp and nop \
+                 can be flipped",
+                "day 08> ip 0, acc 3, 2 instructions executed, the next instruction was executed \
+                 before",
+                "day 08> ",
+            ]
+        );
+
+        let output = session(15, "0,3,6", "help\nturn");
+        assert!(output.contains("  until <turn>"));
+        assert!(output.contains("  quit "));
+        assert!(output.ends_with("day 15> turn 3: 6\nday 15> \n"));
+    }
+
+    #[test]
+    fn test_no_repl() {
+        let explored = aoc_days::find(1).unwrap().puzzle.explore("1", &mut |_| {});
+        assert_eq!(
+            explored.unwrap_err().to_string(),
+            "invalid parameter: day 1 has no REPL"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    any::Any,
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// How a job ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    /// The job panicked with the given message
+    Panicked(String),
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct Completed<T> {
+    pub outcome: Outcome<T>,
+    /// Time until the job finished or, if it timed out, until it was given up on
+    pub duration: Duration,
+}
+
+/// Runs `jobs` on a pool of `workers` threads and returns their outcomes in the order of `jobs`.
+///
+/// Jobs with the same key, such as the parts of a day, share one time limit: `timeout` counts from
+/// when the first of them starts, and those not finished by then are given up on. Each job runs on
+/// a thread of its own, which its worker waits for at most until that deadline before moving on to
+/// the next job. Threads cannot be killed, so a job that timed out keeps running in the background
+/// until it finishes or the process exits.
+pub fn execute<K, T>(
+    jobs: Vec<(K, Job<T>)>,
+    workers: usize,
+    timeout: Option<Duration>,
+) -> Vec<Completed<T>>
+where
+    K: Eq + Hash + Send + 'static,
+    T: Send + 'static,
+{
+    let count = jobs.len();
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let deadlines = Arc::new(Mutex::new(HashMap::new()));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..workers.clamp(1, count.max(1)) {
+        let queue = Arc::clone(&queue);
+        let deadlines = Arc::clone(&deadlines);
+        let sender = sender.clone();
+        thread::spawn(move || loop {
+            let next = queue.lock().unwrap().pop_front();
+            let (idx, (key, job)) = match next {
+                Some(next) => next,
+                None => break,
+            };
+            let deadline = timeout.map(|timeout| {
+                *deadlines
+                    .lock()
+                    .unwrap()
+                    .entry(key)
+                    .or_insert_with(|| Instant::now() + timeout)
+            });
+            if sender.send((idx, run_job(job, deadline))).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    let mut completed = (0..count).map(|_| None).collect::<Vec<_>>();
+    for (idx, job) in receiver {
+        completed[idx] = Some(job);
+    }
+    completed
+        .into_iter()
+        .map(|job| job.expect("every job reports back"))
+        .collect()
+}
+
+fn run_job<T: Send + 'static>(job: Job<T>, deadline: Option<Instant>) -> Completed<T> {
+    let start = Instant::now();
+    // Jobs whose time is up before they start are not started at all
+    if deadline.is_some_and(|deadline| deadline <= start) {
+        return Completed {
+            outcome: Outcome::TimedOut,
+            duration: Duration::ZERO,
+        };
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(job));
+        // The receiver is gone if the job timed out
+        let _ = sender.send(result);
+    });
+
+    let result = match deadline {
+        Some(deadline) => receiver
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            .ok(),
+        None => receiver.recv().ok(),
+    };
+    let outcome = match result {
+        Some(Ok(value)) => Outcome::Finished(value),
+        Some(Err(payload)) => Outcome::Panicked(panic_message(payload.as_ref())),
+        None => Outcome::TimedOut,
+    };
+
+    Completed {
+        outcome,
+        duration: start.elapsed(),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod runner_test {
+    use std::{thread, time::Duration};
+
+    use crate::runner::{execute, Job, Outcome};
+
+    #[test]
+    fn test_execute() {
+        let jobs: Vec<(u8, Job<u32>)> = vec![
+            (
+                1,
+                Box::new(|| {
+                    thread::sleep(Duration::from_millis(50));
+                    1
+                }),
+            ),
+            (2, Box::new(|| panic!("broken"))),
+            (
+                3,
+                Box::new(|| loop {
+                    thread::sleep(Duration::from_millis(10));
+                }),
+            ),
+            (4, Box::new(|| 4)),
+        ];
+
+        let outcomes = execute(jobs, 2, Some(Duration::from_millis(500)))
+            .into_iter()
+            .map(|job| job.outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Finished(1),
+                Outcome::Panicked("broken".to_string()),
+                Outcome::TimedOut,
+                Outcome::Finished(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_shares_timeout() {
+        let sleep = |millis| -> Job<u64> {
+            Box::new(move || {
+                thread::sleep(Duration::from_millis(millis));
+                millis
+            })
+        };
+        // One after another, the jobs of the first key together take longer than the limit
+        let jobs = vec![(1, sleep(300)), (1, sleep(300)), (2, sleep(300))];
+
+        let outcomes = execute(jobs, 1, Some(Duration::from_millis(500)))
+            .into_iter()
+            .map(|job| job.outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Finished(300),
+                Outcome::TimedOut,
+                Outcome::Finished(300),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_without_jobs() {
+        assert!(execute::<(), ()>(Vec::new(), 4, None).is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+use std::{path::PathBuf, sync::Arc, thread, time::Duration};
+
+use aoc_core::{
+    input::{self, InputSource},
+    verify::{Answers, Outcome},
+};
+use clap::Args;
+
+use crate::{
+    runner::{self, Job},
+    select_days, InputArgs,
+};
+
+/// Name the inputs at the top of the input directory are listed under when verifying all users
+const DEFAULT_USER: &str = "(default)";
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Day to verify, defaults to all days
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// File containing the known answers, TOML or JSON depending on its extension. Defaults to the
+    /// `answers.toml` in the input directory, or the one next to the inputs of the selected user
+    #[arg(long, conflicts_with = "all_users")]
+    answers: Option<PathBuf>,
+    /// Verifies the inputs of every user in the input directory against their own answers, and
+    /// the inputs at its top against the `answers.toml` next to them. Days a user has no input for
+    /// are skipped
+    #[arg(long, conflicts_with = "input")]
+    all_users: bool,
+    /// Records the answers of parts missing from the answers file
+    #[arg(short, long)]
+    record: bool,
+    /// Number of parts solved at the same time, defaults to the number of CPUs
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Seconds each day may take for all of its parts together, parts not finished by then are
+    /// given up on
+    #[arg(short, long, default_value_t = 300, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: u64,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Inputs to verify together with the answers known for them
+struct Target {
+    /// User the inputs belong to, only named when verifying all users
+    user: Option<String>,
+    source: InputSource,
+    /// Days without an input are skipped instead of failed, as users need not have all of them
+    skip_missing: bool,
+    answers_path: PathBuf,
+    answers: Answers,
+}
+
+/// Verifies the answers of the selected days, returns false if a part failed
+pub fn verify(args: &VerifyArgs) -> bool {
+    let selection = match select_days(args.day) {
+        Some(selection) => selection,
+        None => return false,
+    };
+
+    let mut targets = match targets(args) {
+        Some(targets) => targets,
+        None => return false,
+    };
+
+    // Every part is solved on the runner, so that a panic or a hanging solver only fails its part
+    let mut tasks = Vec::new();
+    let mut jobs: Vec<(_, Job<Result<String, String>>)> = Vec::new();
+    for (idx, target) in targets.iter().enumerate() {
+        for &day in &selection {
+            if target.skip_missing {
+                if let InputSource::Directory(dir) = &target.source {
+                    if !input::input_path(dir, day.number()).is_file() {
+                        continue;
+                    }
+                }
+            }
+            let input: Result<Arc<str>, String> = target
+                .source
+                .read(day.number())
+                .map(Into::into)
+                .map_err(|err| format!("error while reading input: {}", err));
+
+            for part in day.parts() {
+                let input = input.clone();
+                tasks.push((idx, day.number(), part));
+                jobs.push((
+                    (idx, day.number()),
+                    Box::new(move || {
+                        let report = day.solve(part, &input?).map_err(|err| err.to_string())?;
+                        Ok(report.answer)
+                    }),
+                ));
+            }
+        }
+    }
+
+    let workers = args.jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, |workers| workers.get()),
+        |jobs| jobs as usize,
+    );
+    let completed = runner::execute(jobs, workers, Some(Duration::from_secs(args.timeout)));
+
+    let mut counts = vec![(0, 0, 0); targets.len()];
+    let mut current = None;
+    for ((idx, day, part), job) in tasks.into_iter().zip(completed) {
+        let target = &mut targets[idx];
+        let (passed, failed, missing) = &mut counts[idx];
+        if current != Some(idx) {
+            current = Some(idx);
+            if let Some(user) = &target.user {
+                println!("{}User {}", if idx == 0 { "" } else { "\n" }, user);
+            }
+        }
+
+        let answer = match job.outcome {
+            runner::Outcome::Finished(Ok(answer)) => answer,
+            outcome => {
+                let err = match outcome {
+                    runner::Outcome::Finished(Err(err)) => err,
+                    runner::Outcome::Panicked(message) => format!("panicked: {}", message),
+                    _ => format!("timed out, the day took over {} s", args.timeout),
+                };
+                println!("Day {:02}, Part {}: {:<7} {}", day, part, "FAIL", err);
+                *failed += 1;
+                continue;
+            }
+        };
+
+        let outcome = target.answers.check(day, part, &answer);
+        match &outcome {
+            Outcome::Pass => *passed += 1,
+            Outcome::Fail { .. } => *failed += 1,
+            Outcome::Missing => *missing += 1,
+        }
+
+        let detail = match &outcome {
+            Outcome::Pass => answer.clone(),
+            Outcome::Fail { expected } => format!("expected {}, got {}", expected, answer),
+            Outcome::Missing if args.record => format!("{} (recorded)", answer),
+            Outcome::Missing => answer.clone(),
+        };
+        println!("Day {:02}, Part {}: {:<7} {}", day, part, outcome, detail);
+
+        if outcome == Outcome::Missing && args.record {
+            target.answers.insert(day, part, answer);
+        }
+    }
+
+    let mut success = true;
+    if targets.len() > 1 {
+        println!();
+    }
+    for (target, (passed, failed, missing)) in targets.iter().zip(counts) {
+        let user = target
+            .user
+            .as_ref()
+            .map_or_else(String::new, |user| format!("{}: ", user));
+        println!(
+            "{}{} passed, {} failed, {} missing",
+            user, passed, failed, missing
+        );
+        success &= failed == 0;
+
+        if args.record && missing > 0 {
+            if let Err(err) = target.answers.save(&target.answers_path) {
+                eprintln!("Error while saving answers: {}", err);
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+/// The inputs to verify, those of every user or the ones selected by the input arguments
+fn targets(args: &VerifyArgs) -> Option<Vec<Target>> {
+    let targets: Vec<_> = if args.all_users {
+        let dir = &args.input.input_dir;
+        let users = match input::users(dir) {
+            Ok(users) => users,
+            Err(err) => {
+                eprintln!("Error while listing users: {}", err);
+                return None;
+            }
+        };
+
+        // Inputs at the top of the input directory belong to whoever runs without `--user`
+        let default = (1..=25)
+            .any(|day| input::input_path(dir, day).is_file())
+            .then(|| {
+                (
+                    Some(DEFAULT_USER.to_string()),
+                    InputSource::Directory(dir.clone()),
+                    input::answers_path(dir),
+                    true,
+                )
+            });
+        let users = users.into_iter().map(|user| {
+            let dir = input::user_dir(dir, &user);
+            (
+                Some(user),
+                InputSource::Directory(dir.clone()),
+                input::answers_path(&dir),
+                true,
+            )
+        });
+        let targets = default.into_iter().chain(users).collect::<Vec<_>>();
+
+        if targets.is_empty() {
+            eprintln!(
+                "No users found, their inputs go into {}",
+                input::input_path(&input::user_dir(dir, "<user>"), 1).display()
+            );
+            return None;
+        }
+        targets
+    } else {
+        let answers = args
+            .answers
+            .clone()
+            .unwrap_or_else(|| args.input.answers_path());
+        vec![(None, args.input.input_source(), answers, false)]
+    };
+
+    let mut loaded = Vec::new();
+    for (user, source, answers_path, skip_missing) in targets {
+        match Answers::load(&answers_path) {
+            Ok(answers) => loaded.push(Target {
+                user,
+                source,
+                skip_missing,
+                answers_path,
+                answers,
+            }),
+            Err(err) => {
+                eprintln!("Error while loading answers: {}", err);
+                return None;
+            }
+        }
+    }
+    Some(loaded)
+}
@@ -0,0 +1,315 @@
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use aoc_core::visual::{Frame, Rgb, Sink};
+use clap::Args;
+
+use crate::{read_input, select_days, InputArgs, ParamArgs};
+
+#[derive(Args)]
+pub struct VisualiseArgs {
+    /// Day to visualise, days 11, 12, 17, 20 and 24 support this
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part to visualise
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// File to export the frames to instead of animating them in the terminal, a GIF animation if
+    /// its extension is `.gif` and otherwise a PNG of the last frame
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Milliseconds each frame is shown
+    #[arg(long, default_value_t = 100)]
+    delay: u64,
+    /// Size of the square of pixels a pixel of a frame is exported as
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=16))]
+    scale: u32,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+/// Animates or exports the frames of the selected day, returns false if that failed
+pub fn visualise(args: &VisualiseArgs) -> bool {
+    let day = match select_days(Some(args.day)) {
+        Some(selection) if args.params.check(&selection) => selection[0],
+        _ => return false,
+    };
+    let params = args.params.for_day(day);
+    let input = match read_input(&args.input.input_source(), day) {
+        Some(input) => input,
+        None => return false,
+    };
+    let delay = Duration::from_millis(args.delay);
+
+    let path = match &args.output {
+        Some(path) => path,
+        None => {
+            let mut terminal = Terminal::new(delay);
+            return match day
+                .puzzle
+                .visualise(args.part, &input, &params, &mut terminal)
+            {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("Day {:02}: {}", day.number(), err);
+                    false
+                }
+            };
+        }
+    };
+
+    let mut frames = Vec::new();
+    if let Err(err) = day
+        .puzzle
+        .visualise(args.part, &input, &params, &mut frames)
+    {
+        eprintln!("Day {:02}: {}", day.number(), err);
+        return false;
+    }
+
+    match export(&frames, path, args.scale as usize, delay) {
+        Ok(1) => {
+            println!("Wrote 1 frame to {}", path.display());
+            true
+        }
+        Ok(written) => {
+            println!("Wrote {} frames to {}", written, path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Error while writing {}: {}", path.display(), err);
+            false
+        }
+    }
+}
+
+/// Plays frames back in the terminal as they are rendered
+struct Terminal {
+    delay: Duration,
+    frames: usize,
+}
+
+impl Terminal {
+    fn new(delay: Duration) -> Self {
+        Terminal { delay, frames: 0 }
+    }
+}
+
+impl Sink for Terminal {
+    fn frame(&mut self, frame: Frame) {
+        // The screen is cleared once, later frames are drawn over the previous one
+        let clear = if self.frames == 0 { "\x1b[2J" } else { "" };
+        let mut stdout = io::stdout().lock();
+        let _ = write!(stdout, "{}\x1b[H{}", clear, frame.ansi());
+        let _ = stdout.flush();
+
+        self.frames += 1;
+        thread::sleep(self.delay);
+    }
+}
+
+/// Writes `frames` to `path` and returns how many of them the file holds, which is only the last
+/// one for a PNG
+fn export(
+    frames: &[Frame],
+    path: &Path,
+    scale: usize,
+    delay: Duration,
+) -> Result<usize, Box<dyn Error>> {
+    let file = BufWriter::new(File::create(path)?);
+
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gif"))
+    {
+        write_gif(frames, file, scale, delay)?;
+        Ok(frames.len())
+    } else {
+        let last = frames.last().ok_or("the day did not render any frames")?;
+        write_png(last, file, scale)?;
+        Ok(1)
+    }
+}
+
+fn write_png(frame: &Frame, writer: impl Write, scale: usize) -> Result<(), Box<dyn Error>> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let data = scale_up(frame, scale)
+        .iter()
+        .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+        .collect::<Vec<_>>();
+
+    let mut encoder = png::Encoder::new(writer, u32::try_from(width)?, u32::try_from(height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&data)?;
+
+    Ok(())
+}
+
+/// Writes an endlessly repeating animation.
+///
+/// Frames rarely use more than a handful of colours, so all frames share an exact palette. Only
+/// if there are more colours than a GIF palette holds, each frame gets a palette of its own
+/// approximating its colours.
+fn write_gif(
+    frames: &[Frame],
+    writer: impl Write,
+    scale: usize,
+    delay: Duration,
+) -> Result<(), Box<dyn Error>> {
+    let mut palette = Vec::<Rgb>::new();
+    for pixel in frames.iter().flat_map(Frame::pixels) {
+        if palette.len() > 256 {
+            break;
+        }
+        if !palette.contains(pixel) {
+            palette.push(*pixel);
+        }
+    }
+    let exact = palette.len() <= 256;
+    let global_palette = if exact {
+        palette
+            .iter()
+            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0) * scale;
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0) * scale;
+    let mut encoder = gif::Encoder::new(
+        writer,
+        u16::try_from(width)?,
+        u16::try_from(height)?,
+        &global_palette,
+    )?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in frames {
+        let (width, height) = (
+            u16::try_from(frame.width() * scale)?,
+            u16::try_from(frame.height() * scale)?,
+        );
+        let pixels = scale_up(frame, scale);
+
+        let mut gif_frame = if exact {
+            let indices = pixels
+                .iter()
+                .map(|pixel| palette.iter().position(|color| color == pixel).unwrap() as u8)
+                .collect::<Vec<_>>();
+            gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(indices),
+                ..gif::Frame::default()
+            }
+        } else {
+            let rgb = pixels
+                .iter()
+                .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+                .collect::<Vec<_>>();
+            gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+        };
+        // GIF delays are given in hundredths of a second
+        gif_frame.delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+        encoder.write_frame(&gif_frame)?;
+    }
+
+    Ok(())
+}
+
+/// The pixels of `frame`, row by row, each repeated `scale` times in both directions
+fn scale_up(frame: &Frame, scale: usize) -> Vec<Rgb> {
+    frame
+        .pixels()
+        .chunks(frame.width().max(1))
+        .flat_map(|row| {
+            let row = row
+                .iter()
+                .flat_map(|pixel| std::iter::repeat_n(*pixel, scale))
+                .collect::<Vec<_>>();
+            std::iter::repeat_n(row, scale).flatten()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod visualise_test {
+    use std::{env, fs, process, time::Duration};
+
+    use aoc_core::visual::{Frame, Rgb};
+
+    use crate::visualise::{export, write_gif, write_png};
+
+    fn frame(color: Rgb) -> Frame {
+        let mut frame = Frame::new(3, 2, Rgb::BLACK);
+        frame.set(2, 1, color);
+        frame
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut png = Vec::new();
+        write_png(&frame(Rgb::RED), &mut png, 2).unwrap();
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (6, 4));
+        // The last two pixels of the first two rows are black, those of the last two rows red
+        assert_eq!(&data[info.line_size * 2 - 6..info.line_size * 2], [0; 6]);
+        assert_eq!(
+            &data[info.line_size * 3 - 6..info.line_size * 3],
+            [220, 50, 47, 220, 50, 47]
+        );
+        assert_eq!(&data[info.line_size * 4 - 6..], [220, 50, 47, 220, 50, 47]);
+    }
+
+    #[test]
+    fn test_export() {
+        let frames = vec![frame(Rgb::RED), frame(Rgb::GREEN)];
+        let delay = Duration::from_millis(250);
+        let dir = env::temp_dir().join(format!("aoc_export_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // A GIF holds every frame, a PNG only the last
+        assert_eq!(export(&frames, &dir.join("a.gif"), 1, delay).unwrap(), 2);
+        assert_eq!(export(&frames, &dir.join("a.png"), 1, delay).unwrap(), 1);
+        assert!(export(&[], &dir.join("b.png"), 1, delay).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let frames = vec![frame(Rgb::RED), frame(Rgb::GREEN)];
+        let mut gif = Vec::new();
+        write_gif(&frames, &mut gif, 2, Duration::from_millis(250)).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+
+        let mut last_pixels = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 25);
+            last_pixels.push(frame.buffer[frame.buffer.len() - 4..].to_vec());
+        }
+        assert_eq!(
+            last_pixels,
+            vec![vec![220, 50, 47, 255], vec![133, 153, 0, 255]]
+        );
+    }
+}
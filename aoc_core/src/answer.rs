@@ -0,0 +1,78 @@
+use std::{convert::Infallible, fmt::Display};
+
+use serde_json::Value;
+
+/// Answer of a part of a puzzle.
+///
+/// Displaying it gives the answer to submit, `details` exposes the intermediate results that led
+/// to it for machine-readable output.
+pub trait Answer: Display {
+    fn details(&self) -> Option<Value> {
+        None
+    }
+}
+
+macro_rules! plain_answers {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Answer for $ty {})*
+    };
+}
+
+plain_answers![i32, i64, isize, u32, u64, usize, String, Infallible];
+
+/// An answer formatted for output, together with its intermediate results
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub answer: String,
+    pub details: Option<Value>,
+}
+
+impl Report {
+    pub fn new(answer: &impl Answer) -> Self {
+        Report {
+            answer: answer.to_string(),
+            details: answer.details(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod answer_test {
+    use std::fmt;
+
+    use serde_json::{json, Value};
+
+    use crate::answer::{Answer, Report};
+
+    struct Pair(u32, u32);
+
+    impl fmt::Display for Pair {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0 * self.1)
+        }
+    }
+
+    impl Answer for Pair {
+        fn details(&self) -> Option<Value> {
+            Some(json!([self.0, self.1]))
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            Report::new(&42usize),
+            Report {
+                answer: "42".to_string(),
+                details: None
+            }
+        );
+        assert_eq!(
+            Report::new(&Pair(3, 4)),
+            Report {
+                answer: "12".to_string(),
+                details: Some(json!([3, 4]))
+            }
+        );
+    }
+}
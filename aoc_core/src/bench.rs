@@ -0,0 +1,217 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{Puzzle, Result};
+
+/// Time spent in each stage of a single run of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    /// One entry per part of the day
+    pub parts: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn stages(&self) -> impl Iterator<Item = (Stage, Duration)> + '_ {
+        let parts = self
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(idx, duration)| (Stage::Part(idx as u8 + 1), *duration));
+        Some((Stage::Parse, self.parse)).into_iter().chain(parts)
+    }
+}
+
+/// Stages of a day which are timed separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Stage::Part)
+                .ok_or_else(|| format!("unknown stage {:?}", s)),
+        }
+    }
+}
+
+/// Summary of the durations measured over several iterations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, returns `None` if there are none
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        let median = match samples.len() {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+/// Runs `puzzle` on `input` `iterations` times and summarises the timings of each stage
+pub fn measure(puzzle: &dyn Puzzle, input: &str, iterations: usize) -> Result<Vec<(Stage, Stats)>> {
+    let mut samples = BTreeMap::<Stage, Vec<Duration>>::new();
+
+    for _ in 0..iterations {
+        for (stage, duration) in puzzle.time(input)?.stages() {
+            samples.entry(stage).or_default().push(duration);
+        }
+    }
+
+    Ok(samples
+        .iter()
+        .filter_map(|(stage, samples)| Some((*stage, Stats::from_samples(samples)?)))
+        .collect())
+}
+
+/// Median timings of a previous benchmark run, to detect regressions against.
+///
+/// Stored as plain text with one `<day> <stage> <nanoseconds>` line per stage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        fs::read_to_string(path)?.parse().map_err(|err| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{} {} {}", day, stage, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Baseline::default();
+
+        for (idx, line) in s.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let invalid = || format!("line {}: expected \"<day> <stage> <nanoseconds>\"", idx + 1);
+
+            match fields[..] {
+                [day, stage, nanos] => baseline.insert(
+                    day.parse().map_err(|_| invalid())?,
+                    stage.parse().map_err(|_| invalid())?,
+                    Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+                ),
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(baseline)
+    }
+}
+
+/// Relative change of `current` compared to `baseline`, `0.1` meaning 10% slower
+pub fn relative_change(current: Duration, baseline: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+    current.as_secs_f64() / baseline.as_secs_f64() - 1.0
+}
+
+#[cfg(test)]
+mod bench_test {
+    use std::time::Duration;
+
+    use crate::bench::{relative_change, Baseline, Stage, Stats};
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut baseline = Baseline::default();
+        baseline.insert(15, Stage::Part(2), Duration::from_millis(800));
+        baseline.insert(1, Stage::Parse, Duration::from_nanos(1234));
+
+        let raw = baseline.to_string();
+        assert_eq!(raw, "1 parse 1234\n15 part2 800000000\n");
+        assert_eq!(raw.parse::<Baseline>().unwrap(), baseline);
+        assert!("1 parse".parse::<Baseline>().is_err());
+        assert!("1 solve 12".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn test_relative_change() {
+        let change = relative_change(Duration::from_millis(110), Duration::from_millis(100));
+        assert!((change - 0.1).abs() < 1e-9);
+        assert_eq!(
+            relative_change(Duration::from_millis(1), Duration::ZERO),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::{error, fmt, io, str::FromStr};
+
+/// Errors shared by all days' solvers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The puzzle input is malformed, `line` and `column` are 1-based
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The puzzle has no solution for the given input
+    NoSolution,
+    /// A parameter of the puzzle is invalid for the given input
+    InvalidParameter(String),
+    /// Reading the puzzle input failed, only while streaming it
+    Read(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a parse error pointing at `token`, which has to be a slice of `input`.
+    ///
+    /// Line and column are derived from the position of `token` within `input`, tokens which do
+    /// not belong to `input` are reported at its end.
+    pub fn parse_at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Error::parse(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    /// Creates a parse error for a token which is missing at the end of `line`, a slice of `input`
+    pub fn missing(input: &str, line: &str, what: &str) -> Self {
+        Error::parse_at(input, &line[line.len()..], format!("missing {}", what))
+    }
+
+    pub fn invalid_parameter(message: impl Into<String>) -> Self {
+        Error::InvalidParameter(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::NoSolution => write!(f, "no solution found"),
+            Error::InvalidParameter(message) => write!(f, "invalid parameter: {}", message),
+            Error::Read(message) => write!(f, "error while reading input: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Read(err.to_string())
+    }
+}
+
+/// Parses `token`, a slice of `input`, reporting its position on failure
+pub fn parse<T>(input: &str, token: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse()
+        .map_err(|err| Error::parse_at(input, token, format!("{:?}: {}", token, err)))
+}
+
+#[cfg(test)]
+mod error_test {
+    use crate::error::{parse, Error};
+
+    #[test]
+    fn test_parse_at() {
+        let input = "1-3 a: abcde\n1-x b: cdefg";
+        let line = input.lines().nth(1).unwrap();
+
+        assert_eq!(
+            Error::parse_at(input, &line[2..3], "expected a number"),
+            Error::parse(2, 3, "expected a number")
+        );
+        assert_eq!(
+            Error::missing(input, line, "password"),
+            Error::parse(2, 13, "missing password")
+        );
+        assert_eq!(
+            Error::parse_at(input, "elsewhere", "foreign token").to_string(),
+            "parse error at line 2, column 13: foreign token"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "12,äb,7";
+        let tokens = input.split(',').collect::<Vec<_>>();
+
+        assert_eq!(parse::<u8>(input, tokens[0]), Ok(12));
+        assert_eq!(
+            parse::<u8>(input, tokens[1]),
+            Err(Error::parse(1, 4, "\"äb\": invalid digit found in string"))
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Examples of the puzzle descriptions, extracted from locally saved puzzle pages into fixtures
+//! which the tests run for every day.
+//!
+//! The fixtures of a day live in `examples/day_XX/`: the inputs as `example_1.txt`, `example_2.txt`
+//! and so on, next to an `examples.toml` with the answers the description gives for them, e.g.
+//! `[example_1]` with `part_1 = "37"`. Examples which only hold for other values of the puzzle's
+//! constants also list these, e.g. `[example_2.params]` with `window = "5"` for day 9.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+/// Directory of the fixtures, relative to the working directory
+pub const DEFAULT_EXAMPLES_DIR: &str = "examples";
+/// File holding the answers and parameters of a day's examples
+const ANSWERS_FILE: &str = "examples.toml";
+
+/// Example input of a puzzle together with what is known about it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// Answers given by the description, by part
+    pub answers: BTreeMap<u8, String>,
+    /// Parameters to solve the example with, as taken by
+    /// [`Puzzle::solve_with`](crate::Puzzle::solve_with)
+    pub params: Vec<(String, String)>,
+}
+
+/// What [`extract`] found on a puzzle page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    /// Day named in the title of the page
+    pub day: Option<u8>,
+    pub examples: Vec<Example>,
+}
+
+/// Extracts the examples of a puzzle page as saved from the browser.
+///
+/// Every `<pre><code>` block is an example. Each part of the puzzle is described in an `<article>`
+/// of its own, whose last emphasised code, such as `<code><em>37</em></code>`, is taken as the
+/// answer to its first example. Descriptions of part 2 without an example of their own refer to
+/// the first example of the page.
+pub fn extract(html: &str) -> Page {
+    let day = html
+        .split("--- Day ")
+        .nth(1)
+        .and_then(|title| title.split(':').next())
+        .and_then(|day| day.trim().parse().ok());
+
+    let mut articles = elements(html, "<article", "</article>");
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut examples = Vec::<Example>::new();
+    for (part, article) in (1..).zip(articles) {
+        let blocks = elements(article, "<pre><code", "</code></pre>")
+            .into_iter()
+            .map(text)
+            .collect::<Vec<_>>();
+        for block in &blocks {
+            if !examples.iter().any(|example| example.input == *block) {
+                examples.push(Example {
+                    input: block.clone(),
+                    ..Example::default()
+                });
+            }
+        }
+
+        let input = blocks
+            .first()
+            .or_else(|| examples.first().map(|example| &example.input))
+            .cloned();
+        if let (Some(input), Some(answer)) = (input, last_emphasised(article)) {
+            let example = examples
+                .iter_mut()
+                .find(|example| example.input == input)
+                .expect("the inputs of all blocks are examples");
+            example.answers.insert(part, answer);
+        }
+    }
+
+    Page { day, examples }
+}
+
+/// Directory of the fixtures of `day` inside `dir`
+pub fn day_dir(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day_{:02}", day))
+}
+
+/// Loads the fixtures of `day`, a day without any has no examples
+pub fn load(dir: &Path, day: u8) -> io::Result<Vec<Example>> {
+    let dir = day_dir(dir, day);
+    let raw = match fs::read_to_string(dir.join(ANSWERS_FILE)) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let invalid = |message: String| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: {}", dir.join(ANSWERS_FILE).display(), message),
+        )
+    };
+
+    let value = toml::from_str::<Value>(&raw).map_err(|err| invalid(err.to_string()))?;
+    let tables = value
+        .as_object()
+        .ok_or_else(|| invalid("expected a table of examples".into()))?;
+
+    let mut examples = BTreeMap::new();
+    for (key, table) in tables {
+        let number = parse_key(key, "example_").map_err(invalid)?;
+        let table = table
+            .as_object()
+            .ok_or_else(|| invalid(format!("{}: expected a table", key)))?;
+
+        let mut example = Example {
+            input: fs::read_to_string(dir.join(format!("{}.txt", key)))?,
+            ..Example::default()
+        };
+        for (name, value) in table {
+            match (name.as_str(), value) {
+                ("params", Value::Object(params)) => {
+                    for (name, value) in params {
+                        let value = scalar(value).ok_or_else(|| {
+                            invalid(format!("{}.params.{}: expected a value", key, name))
+                        })?;
+                        example.params.push((name.clone(), value));
+                    }
+                }
+                (part, value) => {
+                    let part = parse_key(part, "part_").map_err(invalid)?;
+                    let answer = scalar(value)
+                        .ok_or_else(|| invalid(format!("{}.{}: expected an answer", key, name)))?;
+                    example.answers.insert(part, answer);
+                }
+            }
+        }
+        examples.insert(number, example);
+    }
+
+    Ok(examples.into_values().collect())
+}
+
+/// Saves `examples` as the fixtures of `day`, replacing those there were
+pub fn save(dir: &Path, day: u8, examples: &[Example]) -> io::Result<()> {
+    let dir = day_dir(dir, day);
+    fs::create_dir_all(&dir)?;
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if name.starts_with("example_") && name.ends_with(".txt") {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    let mut tables = Map::new();
+    for (number, example) in (1..).zip(examples) {
+        let key = format!("example_{}", number);
+        fs::write(dir.join(format!("{}.txt", key)), &example.input)?;
+
+        let mut table = Map::new();
+        for (part, answer) in &example.answers {
+            table.insert(format!("part_{}", part), Value::String(answer.clone()));
+        }
+        if !example.params.is_empty() {
+            let params = example
+                .params
+                .iter()
+                .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+                .collect();
+            table.insert("params".into(), Value::Object(params));
+        }
+        tables.insert(key, Value::Object(table));
+    }
+
+    let raw = toml::to_string(&Value::Object(tables))
+        .map_err(|err| io::Error::new(ErrorKind::InvalidData, err.to_string()))?;
+    fs::write(dir.join(ANSWERS_FILE), raw)
+}
+
+/// The contents of the elements of `html` which start with `open` and end with `close`
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut elements = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        // The opening tag may carry attributes
+        let after = &rest[start + open.len()..];
+        let content = match after.find('>') {
+            Some(end) => &after[end + 1..],
+            None => break,
+        };
+        let end = content.find(close).unwrap_or(content.len());
+        elements.push(&content[..end]);
+        rest = &content[end..];
+    }
+
+    elements
+}
+
+/// The last emphasised code of `html`, which may be emphasised inside or outside of the code
+fn last_emphasised(html: &str) -> Option<String> {
+    elements(html, "<code><em", "</em></code>")
+        .into_iter()
+        .chain(elements(html, "<em><code", "</code></em>"))
+        .max_by_key(|inner| inner.as_ptr() as usize)
+        .map(text)
+}
+
+/// The text of `html` with its tags removed and its character references decoded
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(idx) = rest.find(['<', '&']) {
+        text.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+
+        if rest.starts_with('<') {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let reference = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode(&rest[1..end]).map(|ch| (ch, end)));
+        match reference {
+            Some((ch, end)) => {
+                text.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+
+    text
+}
+
+/// Decodes a character reference without its `&` and `;`
+fn decode(reference: &str) -> Option<char> {
+    match reference {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match reference
+                .strip_prefix("#x")
+                .or_else(|| reference.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => reference.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// Parses keys like `example_2` or `part_1`
+fn parse_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("invalid key {:?}, expected e.g. \"{}1\"", key, prefix))
+}
+
+#[cfg(test)]
+mod examples_test {
+    use std::{env, fs, process};
+
+    use crate::examples::{extract, load, save, text, Example, Page};
+
+    /// Shortened from a saved page of day 1
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2020</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Report Repair ---</h2>
+<p>Specifically, they need you to <em>find the two entries that sum to <code>2020</code></em>.</p>
+<p>For example, suppose your expense report contained the following:</p>
+<pre><code>1721
+979
+366
+299
+675
+1456
+</code></pre>
+<p>Multiplying them together produces <code>1721 * 299 = 514579</code>, so the correct answer is <code><em>514579</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>381699</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the above example again, the three entries that sum to <code>2020</code> are <code>979</code>, <code>366</code>, and <code>675</code>. Multiplying them together produces the answer, <code><em>241861950</em></code>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    fn example(input: &str, answers: &[(u8, &str)]) -> Example {
+        Example {
+            input: input.into(),
+            answers: answers
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect(),
+            params: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PAGE),
+            Page {
+                day: Some(1),
+                examples: vec![example(
+                    "1721\n979\n366\n299\n675\n1456\n",
+                    &[(1, "514579"), (2, "241861950")]
+                )],
+            }
+        );
+
+        // Answers belong to the first example of their part, others are kept without answers
+        let page = "<article><pre><code>a &lt;b&gt;</code></pre><pre><code>c</code></pre>\
+                    <em><code>1</code></em></article>\
+                    <article><pre><code>d</code></pre><code><em>2</em></code></article>";
+        assert_eq!(
+            extract(page).examples,
+            vec![
+                example("a <b>", &[(1, "1")]),
+                example("c", &[]),
+                example("d", &[(2, "2")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            text("<em>#</em>.#&amp;&#x41;&#66; &bogus; &"),
+            "#.#&AB &bogus; &"
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = env::temp_dir().join(format!("aoc_examples_{}", process::id()));
+        let mut examples = vec![
+            example("1\n2\n", &[(1, "3"), (2, "2")]),
+            example("x = '''\n", &[(1, "\"quoted\"")]),
+        ];
+        examples[1].params.push(("window".into(), "5".into()));
+
+        assert_eq!(load(&dir, 9).unwrap(), Vec::new());
+        save(&dir, 9, &examples).unwrap();
+        assert_eq!(load(&dir, 9).unwrap(), examples);
+
+        // Saving again replaces the fixtures there were
+        save(&dir, 9, &examples[1..]).unwrap();
+        assert_eq!(load(&dir, 9).unwrap(), examples[1..]);
+        assert!(!dir.join("day_09/example_2.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
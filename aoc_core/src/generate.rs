@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Randomly generated puzzle input, together with the answers known from its construction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    /// One entry per part, `None` if the generator does not know the answer
+    pub answers: Vec<Option<String>>,
+}
+
+impl Generated {
+    pub fn new(input: impl Into<String>) -> Self {
+        Generated {
+            input: input.into(),
+            answers: Vec::new(),
+        }
+    }
+
+    pub fn with_answer(mut self, part: u8, answer: impl Display) -> Self {
+        let idx = part as usize - 1;
+        if self.answers.len() <= idx {
+            self.answers.resize(idx + 1, None);
+        }
+        self.answers[idx] = Some(answer.to_string());
+        self
+    }
+
+    /// Known answer of `part`, if any
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers
+            .get(part as usize - 1)
+            .and_then(|answer| answer.as_deref())
+    }
+}
+
+/// Calls `generate` until it returns a value, for generators which cannot construct valid inputs
+/// directly and have to discard invalid ones
+pub fn retry<T>(rng: &mut StdRng, mut generate: impl FnMut(&mut StdRng) -> Option<T>) -> T {
+    loop {
+        if let Some(value) = generate(rng) {
+            return value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod generate_test {
+    use crate::generate::Generated;
+
+    #[test]
+    fn test_answers() {
+        let generated = Generated::new("1\n2").with_answer(2, 42);
+        assert_eq!(generated.answer(1), None);
+        assert_eq!(generated.answer(2), Some("42"));
+        assert_eq!(generated.answer(3), None);
+    }
+}
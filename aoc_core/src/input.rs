@@ -0,0 +1,205 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to the directory containing the puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Input directory used if [`INPUT_DIR_VAR`] is not set, relative to the working directory
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+/// Environment variable naming the user whose inputs to read from the input directory
+pub const USER_VAR: &str = "AOC_USER";
+/// File next to the inputs, in the input directory or a user's, which holds the answers known for
+/// them
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Where to read a day's puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A single input file
+    File(PathBuf),
+    /// Standard input
+    Stdin,
+    /// A directory containing one `day_XX.txt` file per day
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets a command line argument, `-` stands for stdin and no argument for the input
+    /// directory
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::Directory(input_dir()),
+        }
+    }
+
+    /// Reads the input of `day`, which is only needed to pick the file inside a directory
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Directory(dir) => read_file(&input_path(dir, day)),
+        }
+    }
+
+    /// Opens the input of `day` for reading it bit by bit instead of all at once
+    pub fn open(&self, day: u8) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::File(path) => open_file(path),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Directory(dir) => open_file(&input_path(dir, day)),
+        }
+    }
+}
+
+/// Input directory configured through [`INPUT_DIR_VAR`], falling back to [`DEFAULT_INPUT_DIR`]
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Path of the input of `day` inside `dir`
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day_{:02}.txt", day))
+}
+
+/// Directory of the inputs of `user` inside the input directory `dir`
+pub fn user_dir(dir: &Path, user: &str) -> PathBuf {
+    dir.join(user)
+}
+
+/// Path of the answers known for the inputs inside `dir`
+pub fn answers_path(dir: &Path) -> PathBuf {
+    dir.join(ANSWERS_FILE)
+}
+
+/// Users with inputs of their own in the input directory `dir`, which are the names of its
+/// subdirectories in alphabetical order. Hidden directories are skipped.
+pub fn users(dir: &Path) -> io::Result<Vec<String>> {
+    let mut users = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|err| with_path(dir, err))? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        match entry.file_name().into_string() {
+            Ok(name) if !name.starts_with('.') => users.push(name),
+            _ => {}
+        }
+    }
+    users.sort();
+    Ok(users)
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|err| with_path(path, err))
+}
+
+fn open_file(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let file = File::open(path).map_err(|err| with_path(path, err))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn with_path(path: &Path, err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod input_test {
+    use std::{
+        env, fs,
+        io::Read,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use crate::{
+        input::{answers_path, input_path, user_dir, users, InputSource},
+        verify::Answers,
+    };
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("day_01.txt")),
+            InputSource::File(PathBuf::from("day_01.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(Path::new("inputs"), 7),
+            Path::new("inputs").join("day_07.txt")
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+        let input = InputSource::Directory(dir.clone()).read(15).unwrap();
+        assert_eq!(input.trim(), "14,8,16,0,1,17");
+        assert_eq!(
+            InputSource::File(input_path(&dir, 15)).read(0).unwrap(),
+            input
+        );
+
+        let err = InputSource::Directory(dir).read(26).unwrap_err();
+        assert!(err.to_string().contains("day_26.txt"));
+    }
+
+    #[test]
+    fn test_answers_path() {
+        // The answers are looked up next to the inputs, wherever the working directory is
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+        let answers = Answers::load(&answers_path(&dir)).unwrap();
+        assert_eq!(answers.get(15, 1), Some("240"));
+
+        assert_eq!(
+            answers_path(&user_dir(Path::new("inputs"), "bob")),
+            Path::new("inputs").join("bob").join("answers.toml")
+        );
+    }
+
+    #[test]
+    fn test_users() {
+        let dir = env::temp_dir().join(format!("aoc_users_{}", process::id()));
+        for user in ["bob", "alice", ".git"] {
+            fs::create_dir_all(user_dir(&dir, user)).unwrap();
+        }
+        fs::write(input_path(&dir, 1), "1721").unwrap();
+        fs::write(input_path(&user_dir(&dir, "alice"), 1), "979").unwrap();
+
+        assert_eq!(users(&dir).unwrap(), vec!["alice", "bob"]);
+        let alice = InputSource::Directory(user_dir(&dir, "alice"));
+        assert_eq!(alice.read(1).unwrap(), "979");
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(users(&dir).is_err());
+    }
+
+    #[test]
+    fn test_open() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+        let mut input = String::new();
+        InputSource::Directory(dir.clone())
+            .open(15)
+            .unwrap()
+            .read_to_string(&mut input)
+            .unwrap();
+        assert_eq!(input.trim(), "14,8,16,0,1,17");
+
+        let err = InputSource::Directory(dir).open(26).err().unwrap();
+        assert!(err.to_string().contains("day_26.txt"));
+    }
+}
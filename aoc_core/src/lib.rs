@@ -0,0 +1,486 @@
+use std::{env, hint::black_box, io::BufRead, marker::PhantomData, process, time::Instant};
+
+pub mod answer;
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod input;
+pub mod normalize;
+pub mod params;
+pub mod repl;
+pub mod stream;
+pub mod trace;
+pub mod verify;
+pub mod visual;
+
+pub use answer::{Answer, Report};
+use bench::Timings;
+pub use error::{Error, Result};
+use generate::{Generated, SeedableRng, StdRng};
+use input::InputSource;
+use params::{Param, Params};
+use repl::Session;
+use visual::Sink;
+
+/// Common interface of all days' solvers.
+///
+/// `parse` turns the raw puzzle input into whatever representation the day works on, which both
+/// parts then share.
+pub trait Solution {
+    /// Day of the puzzle, between 1 and 25
+    const DAY: u8;
+    /// Title of the puzzle
+    const TITLE: &'static str;
+    /// Number of parts of the puzzle, day 25 only has one
+    const PARTS: u8 = 2;
+
+    /// Parsed puzzle input, which may borrow from the raw input
+    type Input<'a>;
+    /// Tunable constants of the puzzle, declared with [`params!`](crate::params!), or `()` if it has none
+    type Params: Params;
+    type Output1: Answer;
+    type Output2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1>;
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2>;
+
+    /// Generates a random valid input
+    fn generate(rng: &mut StdRng) -> Generated;
+
+    /// Renders the steps of solving `part` as frames, only some days support this
+    fn visualise(
+        _input: &Self::Input<'_>,
+        _params: &Self::Params,
+        _part: u8,
+        _sink: &mut dyn Sink,
+    ) -> Result<()> {
+        Err(Error::invalid_parameter(format!(
+            "day {} has no visualisation",
+            Self::DAY
+        )))
+    }
+
+    /// Starts a session for exploring the parsed input interactively, only some days support this
+    fn explore<'a>(_input: &'a Self::Input<'_>) -> Result<Box<dyn Session + 'a>> {
+        Err(Error::invalid_parameter(format!(
+            "day {} has no REPL",
+            Self::DAY
+        )))
+    }
+
+    /// Parses `input` and solves part 1 with the default parameters
+    fn solve_part1(input: &str) -> Result<Self::Output1> {
+        Self::solve_part1_with(input, &Self::Params::default())
+    }
+
+    /// Parses `input` and solves part 2 with the default parameters
+    fn solve_part2(input: &str) -> Result<Self::Output2> {
+        Self::solve_part2_with(input, &Self::Params::default())
+    }
+
+    fn solve_part1_with(input: &str, params: &Self::Params) -> Result<Self::Output1> {
+        let input = Self::parse_traced(input)?;
+        let _span = trace::span("part 1");
+        Self::part1(&input, params)
+    }
+
+    fn solve_part2_with(input: &str, params: &Self::Params) -> Result<Self::Output2> {
+        let input = Self::parse_traced(input)?;
+        let _span = trace::span("part 2");
+        Self::part2(&input, params)
+    }
+
+    /// Solves part 1 reading the input from `reader`.
+    ///
+    /// By default the whole input is read and parsed as usual, days with line oriented inputs
+    /// override this to process the lines as they are read, see [`stream`].
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        Self::solve_part1_with(&stream::read_all(reader)?, params)
+    }
+
+    /// Solves part 2 reading the input from `reader`, see [`stream_part1`](Solution::stream_part1)
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        Self::solve_part2_with(&stream::read_all(reader)?, params)
+    }
+
+    /// Parses `input` within a span of its own
+    fn parse_traced(input: &str) -> Result<Self::Input<'_>> {
+        let _span = trace::span("parse");
+        Self::parse(input)
+    }
+}
+
+/// Object safe counterpart of [`Solution`], so that all days can be stored side by side
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parts(&self) -> u8;
+    /// Parameters of the puzzle which [`solve_with`](Puzzle::solve_with) accepts
+    fn params(&self) -> Vec<Param>;
+    /// Parses `input` without solving anything, to check whether it is valid
+    fn validate(&self, input: &str) -> Result<()>;
+    /// Parses `input` and solves `part` of the puzzle with the default parameters
+    fn solve(&self, part: u8, input: &str) -> Result<Report> {
+        self.solve_with(part, input, &[])
+    }
+    /// Parses `input` and solves `part` of the puzzle, setting the parameters named in
+    /// `params` to the values given as strings
+    fn solve_with(&self, part: u8, input: &str, params: &[(String, String)]) -> Result<Report>;
+    /// Solves `part` of the puzzle reading the input from `reader`, line oriented days only keep
+    /// what they need of it in memory
+    fn stream(
+        &self,
+        part: u8,
+        reader: &mut dyn BufRead,
+        params: &[(String, String)],
+    ) -> Result<Report>;
+    /// Parses `input` and solves all parts, timing each of these stages separately
+    fn time(&self, input: &str) -> Result<Timings>;
+    /// Generates a random valid input, the same `seed` always leading to the same input
+    fn generate(&self, seed: u64) -> Generated;
+    /// Parses `input` and hands the frames showing how `part` is solved to `sink`
+    fn visualise(
+        &self,
+        part: u8,
+        input: &str,
+        params: &[(String, String)],
+        sink: &mut dyn Sink,
+    ) -> Result<()>;
+    /// Parses `input` and hands a session exploring it to `f`
+    fn explore(&self, input: &str, f: &mut dyn FnMut(&mut dyn Session)) -> Result<()>;
+}
+
+/// Wraps a [`Solution`] to implement [`Puzzle`] for it
+pub struct Solver<S>(PhantomData<fn() -> S>);
+
+impl<S> Solver<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Solver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Puzzle for Solver<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn validate(&self, input: &str) -> Result<()> {
+        S::parse(input).map(|_| ())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        S::Params::describe()
+    }
+
+    fn solve_with(&self, part: u8, input: &str, params: &[(String, String)]) -> Result<Report> {
+        let params = configure::<S>(params)?;
+        match part {
+            1 => S::solve_part1_with(input, &params).map(|answer| Report::new(&answer)),
+            2 if S::PARTS == 2 => {
+                S::solve_part2_with(input, &params).map(|answer| Report::new(&answer))
+            }
+            _ => Err(no_such_part::<S>(part)),
+        }
+    }
+
+    fn stream(
+        &self,
+        part: u8,
+        reader: &mut dyn BufRead,
+        params: &[(String, String)],
+    ) -> Result<Report> {
+        let params = configure::<S>(params)?;
+        match part {
+            1 => S::stream_part1(reader, &params).map(|answer| Report::new(&answer)),
+            2 if S::PARTS == 2 => {
+                S::stream_part2(reader, &params).map(|answer| Report::new(&answer))
+            }
+            _ => Err(no_such_part::<S>(part)),
+        }
+    }
+
+    fn time(&self, input: &str) -> Result<Timings> {
+        let params = S::Params::default();
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part1(&input, &params)?);
+        let mut parts = vec![start.elapsed()];
+
+        if S::PARTS == 2 {
+            let start = Instant::now();
+            black_box(S::part2(&input, &params)?);
+            parts.push(start.elapsed());
+        }
+
+        Ok(Timings { parse, parts })
+    }
+
+    fn generate(&self, seed: u64) -> Generated {
+        S::generate(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn visualise(
+        &self,
+        part: u8,
+        input: &str,
+        params: &[(String, String)],
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        if part == 0 || part > S::PARTS {
+            return Err(no_such_part::<S>(part));
+        }
+        let params = configure::<S>(params)?;
+        S::visualise(&S::parse(input)?, &params, part, sink)
+    }
+
+    fn explore(&self, input: &str, f: &mut dyn FnMut(&mut dyn Session)) -> Result<()> {
+        let input = S::parse(input)?;
+        f(S::explore(&input)?.as_mut());
+        Ok(())
+    }
+}
+
+/// The default parameters of `S` with the named ones set to the given values
+fn configure<S: Solution>(params: &[(String, String)]) -> Result<S::Params> {
+    let known = S::Params::describe();
+    if let Some((name, _)) = params
+        .iter()
+        .find(|(name, _)| !known.iter().any(|param| param.name == name))
+    {
+        return Err(Error::invalid_parameter(format!(
+            "day {} has no parameter {:?}",
+            S::DAY,
+            name
+        )));
+    }
+
+    S::Params::with(params)
+}
+
+fn no_such_part<S: Solution>(part: u8) -> Error {
+    Error::invalid_parameter(format!("day {} has no part {}", S::DAY, part))
+}
+
+/// Solves all parts of a day and prints the answers, used by every day's own binary
+pub fn run<S: Solution>(input: &str) -> Result<()> {
+    let input = S::parse(input)?;
+    let params = S::Params::default();
+
+    println!("Part 1: {}", S::part1(&input, &params)?);
+
+    if S::PARTS == 2 {
+        println!("Part 2: {}", S::part2(&input, &params)?);
+    }
+
+    Ok(())
+}
+
+/// Entry point of every day's own binary.
+///
+/// Reads the input from the file given as first argument, from stdin if that argument is `-`, or
+/// otherwise from the input directory, see [`input::input_dir`].
+pub fn run_from_args<S: Solution>() {
+    let arg = env::args().nth(1);
+    let input = match InputSource::from_arg(arg.as_deref()).read(S::DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error while reading input: {}", err);
+            process::exit(1);
+        }
+    };
+
+    if let Err(err) = run::<S>(&input) {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod aoc_core_test {
+    use crate::{
+        error,
+        generate::{Generated, Rng, StdRng},
+        Error, Puzzle, Result, Solution, Solver,
+    };
+
+    struct Sum;
+
+    crate::params! {
+        struct SumParams {
+            /// Added to the sum
+            offset: usize = 0,
+        }
+    }
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+
+        type Input<'a> = Vec<usize>;
+        type Params = SumParams;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            input
+                .split_whitespace()
+                .map(|n| error::parse(input, n))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input<'_>, params: &SumParams) -> Result<Self::Output1> {
+            Ok(input.iter().sum::<usize>() + params.offset)
+        }
+
+        fn part2(input: &Self::Input<'_>, _: &SumParams) -> Result<Self::Output2> {
+            input.iter().max().copied().ok_or(Error::NoSolution)
+        }
+
+        fn generate(rng: &mut StdRng) -> Generated {
+            let numbers = (0..5)
+                .map(|_| rng.gen_range(1..100))
+                .collect::<Vec<usize>>();
+            let input = numbers.iter().map(usize::to_string).collect::<Vec<_>>();
+
+            Generated::new(input.join(" "))
+                .with_answer(1, numbers.iter().sum::<usize>())
+                .with_answer(2, numbers.iter().max().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = Solver::<Sum>::new();
+        assert_eq!(solver.day(), 1);
+        assert_eq!(solver.parts(), 2);
+        assert_eq!(solver.validate("1 2"), Ok(()));
+        assert!(solver.validate("1 x").is_err());
+        assert_eq!(solver.solve(1, "1 2 3").unwrap().answer, "6");
+        assert_eq!(solver.solve(2, "1 2 3").unwrap().answer, "3");
+        assert_eq!(solver.solve(2, ""), Err(Error::NoSolution));
+        assert_eq!(
+            solver.solve(1, "1\n2 a").unwrap_err().to_string(),
+            "parse error at line 2, column 3: \"a\": invalid digit found in string"
+        );
+        assert_eq!(
+            solver.solve(3, "1"),
+            Err(Error::invalid_parameter("day 1 has no part 3"))
+        );
+    }
+
+    #[test]
+    fn test_solve_with() {
+        let solver = Solver::<Sum>::new();
+        assert_eq!(solver.params()[0].name, "offset");
+        assert_eq!(solver.params()[0].default, "0");
+
+        let params = |name: &str, value: &str| vec![(name.to_string(), value.to_string())];
+        assert_eq!(
+            solver
+                .solve_with(1, "1 2 3", &params("offset", "10"))
+                .unwrap()
+                .answer,
+            "16"
+        );
+        assert_eq!(
+            solver.solve_with(1, "1 2 3", &params("offset", "x")),
+            Err(Error::invalid_parameter(
+                "offset = \"x\": invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            solver.solve_with(1, "1 2 3", &params("offsets", "1")),
+            Err(Error::invalid_parameter(
+                "day 1 has no parameter \"offsets\""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let solver = Solver::<Sum>::new();
+        assert_eq!(
+            solver
+                .stream(1, &mut "1 2\n3".as_bytes(), &[])
+                .unwrap()
+                .answer,
+            "6"
+        );
+        assert_eq!(
+            solver
+                .stream(2, &mut "1 2\n3".as_bytes(), &[])
+                .unwrap()
+                .answer,
+            "3"
+        );
+        assert!(matches!(
+            solver.stream(1, &mut &b"1 \xff"[..], &[]),
+            Err(Error::Read(_))
+        ));
+    }
+
+    #[test]
+    fn test_generate() {
+        let solver = Solver::<Sum>::new();
+        let generated = solver.generate(7);
+        assert_eq!(solver.generate(7), generated);
+
+        for part in 1..=2 {
+            let answer = solver.solve(part, &generated.input).unwrap().answer;
+            assert_eq!(Some(answer.as_str()), generated.answer(part));
+        }
+    }
+
+    #[test]
+    fn test_visualise() {
+        let solver = Solver::<Sum>::new();
+        let mut frames = Vec::new();
+        assert_eq!(
+            solver.visualise(1, "1 2", &[], &mut frames),
+            Err(Error::invalid_parameter("day 1 has no visualisation"))
+        );
+        assert_eq!(
+            solver.visualise(3, "1 2", &[], &mut frames),
+            Err(Error::invalid_parameter("day 1 has no part 3"))
+        );
+        assert!(frames.is_empty());
+    }
+
+    #[test]
+    fn test_explore() {
+        let solver = Solver::<Sum>::new();
+        assert_eq!(
+            solver.explore("1 2", &mut |_| unreachable!()),
+            Err(Error::invalid_parameter("day 1 has no REPL"))
+        );
+        assert!(solver.explore("1 x", &mut |_| unreachable!()).is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        let solver = Solver::<Sum>::new();
+        let timings = solver.time("1 2 3").unwrap();
+        assert_eq!(timings.parts.len(), 2);
+        assert_eq!(timings.stages().count(), 3);
+        assert!(solver.time("").is_err());
+    }
+}
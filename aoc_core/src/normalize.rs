@@ -0,0 +1,108 @@
+//! Tolerant views of raw puzzle inputs, which every day's parser goes through.
+//!
+//! Inputs may come with Windows line endings, a byte order mark, trailing whitespace or trailing
+//! blank lines, depending on how they were saved. All functions here only hand out slices of the
+//! raw input, so that [`Error::parse_at`](crate::Error::parse_at) still points at the right line
+//! and column.
+
+/// Byte order mark some editors put at the start of UTF-8 files
+const BOM: char = '\u{feff}';
+
+/// Trims whitespace, including the `\r` of Windows line endings, and byte order marks from both
+/// ends of `text`
+pub fn trim(text: &str) -> &str {
+    text.trim_matches(|ch: char| ch.is_whitespace() || ch == BOM)
+}
+
+/// The trimmed lines of `input`, skipping blank ones
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.split('\n').map(trim).filter(|line| !line.is_empty())
+}
+
+/// Strips the line ending, `\n` or `\r\n`, and a byte order mark from `line`, but keeps any other
+/// whitespace, for lines whose content may end or start with it
+pub fn strip_ending(line: &str) -> &str {
+    let line = line.strip_prefix(BOM).unwrap_or(line);
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// The lines of `input` like [`lines`], but only stripped by [`strip_ending`]. Blank lines are
+/// still skipped
+pub fn untrimmed_lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split('\n')
+        .map(strip_ending)
+        .filter(|line| !trim(line).is_empty())
+}
+
+/// The blocks of `input` separated by one or more blank lines.
+///
+/// Each block spans from the start of its first to the end of its last line, both trimmed, so its
+/// lines still have to be read through [`lines`].
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.split('\n').map(trim).peekable();
+    let offset = move |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.is_empty()).is_some() {}
+
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+            last = line;
+        }
+
+        Some(&input[offset(first)..offset(last) + last.len()])
+    })
+}
+
+#[cfg(test)]
+mod normalize_test {
+    use crate::{
+        normalize::{blocks, lines, strip_ending, trim, untrimmed_lines},
+        Error,
+    };
+
+    #[test]
+    fn test_trim() {
+        assert_eq!(trim("\u{feff}14,8,16\r\n"), "14,8,16");
+        assert_eq!(trim(" \t\r\n"), "");
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "\u{feff}1-3 a: abcde\r\n1-3 b: cdefg  \r\n\r\n";
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            vec!["1-3 a: abcde", "1-3 b: cdefg"]
+        );
+    }
+
+    #[test]
+    fn test_untrimmed_lines() {
+        assert_eq!(strip_ending("\u{feff} a \t\r\n"), " a \t");
+        let input = "\u{feff}1-3 a: abcde \r\n \r\n1-3 b:  cdefg\t\n";
+        assert_eq!(
+            untrimmed_lines(input).collect::<Vec<_>>(),
+            vec!["1-3 a: abcde ", "1-3 b:  cdefg\t"]
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "abc\r\n\r\na\r\nb\r\n \r\n\r\nab\nac\n\n\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<_>>(),
+            vec!["abc", "a\r\nb", "ab\nac"]
+        );
+        assert_eq!(blocks("\n \n").count(), 0);
+
+        // Blocks are slices of the input, errors within them are reported at the right position
+        let block = blocks(input).nth(2).unwrap();
+        assert_eq!(
+            Error::parse_at(input, &block[3..], "unexpected"),
+            Error::parse(8, 1, "unexpected")
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Tunable constants of the puzzles, such as the sum day 1 looks for or the number of cycles of
+//! day 17.
+//!
+//! Every day declares its constants with [`params!`](crate::params!) as a struct of typed fields
+//! with defaults, which the parts receive. Parameters can be set by name from strings, so that the
+//! runner can explore variants of a puzzle from the command line.
+
+use std::{fmt, ops::Deref, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Parameters of a day, `()` for days without any
+pub trait Params: Default {
+    /// Name, description and default value of every parameter
+    fn describe() -> Vec<Param>;
+    /// Parses `value` and assigns it to the parameter `name`
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+
+    /// The defaults with the given parameters set, in order
+    fn with(assignments: &[(String, String)]) -> Result<Self> {
+        let mut params = Self::default();
+        for (name, value) in assignments {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+impl Params for () {
+    fn describe() -> Vec<Param> {
+        Vec::new()
+    }
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<()> {
+        Err(unknown(name))
+    }
+}
+
+/// Description of a single parameter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: String,
+}
+
+/// Declares a struct of parameters and implements [`Params`] for it.
+///
+/// Each field is given with its type and default value, its doc comment becomes the description
+/// of the parameter. Field types have to implement `FromStr` and `Display`.
+///
+/// ```
+/// aoc_core::params! {
+///     pub struct Params {
+///         /// Number of rounds to play
+///         rounds: usize = 100,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                $field:ident: $ty:ty = $default:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct $name {
+            $($(#[doc = $doc])* pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn describe() -> Vec<$crate::params::Param> {
+                let defaults = Self::default();
+                vec![$($crate::params::Param {
+                    name: stringify!($field),
+                    description: concat!($($doc),*).trim(),
+                    default: defaults.$field.to_string(),
+                },)*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> $crate::Result<()> {
+                match name {
+                    $(stringify!($field) => self.$field = $crate::params::parse(name, value)?,)*
+                    _ => return Err($crate::params::unknown(name)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Parses `value` of the parameter `name`
+pub fn parse<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|err| Error::invalid_parameter(format!("{} = {:?}: {}", name, value, err)))
+}
+
+pub fn unknown(name: &str) -> Error {
+    Error::invalid_parameter(format!("unknown parameter {:?}", name))
+}
+
+/// Comma separated list of values, for parameters taking more than one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T> Deref for List<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: FromStr> FromStr for List<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| item.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(List)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, item) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod params_test {
+    use crate::{
+        params::{List, Param, Params},
+        Error,
+    };
+
+    crate::params! {
+        struct Test {
+            /// Number of rounds
+            /// to play
+            rounds: usize = 100,
+            target: String = "shiny gold".into(),
+            steps: List<i32> = List(vec![1, -2]),
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            Test::describe(),
+            vec![
+                Param {
+                    name: "rounds",
+                    description: "Number of rounds to play",
+                    default: "100".into(),
+                },
+                Param {
+                    name: "target",
+                    description: "",
+                    default: "shiny gold".into(),
+                },
+                Param {
+                    name: "steps",
+                    description: "",
+                    default: "1,-2".into(),
+                },
+            ]
+        );
+        assert!(<()>::describe().is_empty());
+    }
+
+    #[test]
+    fn test_with() {
+        let assignments = [("steps", "3, 4,5"), ("rounds", "7"), ("rounds", "8")]
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Test::with(&assignments),
+            Ok(Test {
+                rounds: 8,
+                target: "shiny gold".into(),
+                steps: List(vec![3, 4, 5]),
+            })
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut params = Test::default();
+        params.set("target", "dark olive").unwrap();
+        assert_eq!(params.target, "dark olive");
+        assert_eq!(
+            params.set("rounds", "-1"),
+            Err(Error::invalid_parameter(
+                "rounds = \"-1\": invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            params.set("round", "1"),
+            Err(Error::invalid_parameter("unknown parameter \"round\""))
+        );
+        assert_eq!(
+            ().set("rounds", "1"),
+            Err(Error::invalid_parameter("unknown parameter \"rounds\""))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Interactive sessions for poking at the data structures of a day.
+//!
+//! Days which support it build a [`Session`] from their parsed input, see
+//! [`Solution::explore`](crate::Solution::explore). The `aoc repl` subcommand reads commands line
+//! by line and hands them to the session.
+
+use std::{fmt, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Command a session understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command {
+    pub name: &'static str,
+    /// Arguments as shown by `help`, such as `<colour>` or `[n]`
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// State of a day which can be inspected and stepped through interactively
+pub trait Session {
+    /// Commands the session understands, besides the `help` and `quit` of the REPL itself
+    fn commands(&self) -> &'static [Command];
+    /// Executes `command` with its whitespace separated `args`, returning the text to print
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String>;
+}
+
+/// Error for a command the session does not know
+pub fn unknown(command: &str) -> Error {
+    Error::invalid_parameter(format!(
+        "unknown command {:?}, `help` lists all commands",
+        command
+    ))
+}
+
+/// Parses the argument at `idx`, described as `name` in errors
+pub fn arg<T>(args: &[&str], idx: usize, name: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let arg = args
+        .get(idx)
+        .ok_or_else(|| Error::invalid_parameter(format!("missing {}", name)))?;
+    arg.parse()
+        .map_err(|err| Error::invalid_parameter(format!("{} {:?}: {}", name, arg, err)))
+}
+
+/// Parses the optional argument at `idx`, which is `default` if not given
+pub fn arg_or<T>(args: &[&str], idx: usize, name: &str, default: T) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    if idx < args.len() {
+        arg(args, idx, name)
+    } else {
+        Ok(default)
+    }
+}
+
+#[cfg(test)]
+mod repl_test {
+    use crate::{
+        repl::{arg, arg_or, unknown},
+        Error,
+    };
+
+    #[test]
+    fn test_arg() {
+        let args = ["12", "x"];
+        assert_eq!(arg::<usize>(&args, 0, "turn"), Ok(12));
+        assert_eq!(
+            arg::<usize>(&args, 1, "turn"),
+            Err(Error::invalid_parameter(
+                "turn \"x\": invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            arg::<usize>(&args, 2, "turn"),
+            Err(Error::invalid_parameter("missing turn"))
+        );
+        assert_eq!(arg_or(&args, 2, "turn", 1), Ok(1));
+        assert_eq!(arg_or(&args, 0, "turn", 1), Ok(12));
+        assert_eq!(
+            unknown("stpe").to_string(),
+            "invalid parameter: unknown command \"stpe\", `help` lists all commands"
+        );
+    }
+}
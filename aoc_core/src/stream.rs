@@ -0,0 +1,136 @@
+//! Reading line oriented inputs record by record from any [`BufRead`], for inputs too large to
+//! hold in memory at once.
+//!
+//! Lines are trimmed and blank ones skipped just like [`normalize::lines`] does. Every line is
+//! parsed as an input of its own, [`Line::parse`] moves the errors of the day's parsers to the
+//! line's position within the whole input.
+
+use std::io::BufRead;
+
+use crate::{normalize, Error, Result};
+
+/// A non-blank line read by [`lines`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// 1-based number of the line within the whole input
+    number: usize,
+    /// The line as read, including its line ending
+    raw: String,
+}
+
+impl Line {
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// The trimmed line
+    pub fn text(&self) -> &str {
+        normalize::trim(&self.raw)
+    }
+
+    /// Calls `parse` with the raw and the trimmed line, like the days' parsers take an input and
+    /// one of its lines, and reports parse errors at the line's position within the whole input
+    pub fn parse<'a, T>(&'a self, parse: impl FnOnce(&'a str, &'a str) -> Result<T>) -> Result<T> {
+        parse(&self.raw, self.text()).map_err(|err| match err {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => Error::parse(line + self.number - 1, column, message),
+            err => err,
+        })
+    }
+}
+
+/// The non-blank lines of `reader`, which stop after the first error
+pub fn lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        number: 0,
+        failed: false,
+    }
+}
+
+/// Iterator returned by [`lines`]
+pub struct Lines<R> {
+    reader: R,
+    number: usize,
+    failed: bool,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            let mut raw = String::new();
+            match self.reader.read_line(&mut raw) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.number += 1;
+                    if !normalize::trim(&raw).is_empty() {
+                        return Some(Ok(Line {
+                            number: self.number,
+                            raw,
+                        }));
+                    }
+                }
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err.into()));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Reads all of `reader`, for days which need their whole input anyway
+pub fn read_all(reader: &mut dyn BufRead) -> Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod stream_test {
+    use crate::{
+        error,
+        stream::{lines, read_all},
+        Error,
+    };
+
+    #[test]
+    fn test_lines() {
+        let input = "\u{feff}12\r\n\r\n  7 \n\n";
+        let lines = lines(input.as_bytes())
+            .map(|line| line.map(|line| (line.number(), line.text().to_string())))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(lines, Ok(vec![(1, "12".into()), (3, "7".into())]));
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "12\n\n  x7\n";
+        let numbers = lines(input.as_bytes())
+            .map(|line| line?.parse(error::parse::<u32>))
+            .collect::<Vec<_>>();
+        assert_eq!(numbers[0], Ok(12));
+        assert_eq!(
+            numbers[1],
+            Err(Error::parse(3, 3, "\"x7\": invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let invalid: &[u8] = b"12\n\xff\n7\n";
+        let mut lines = lines(invalid);
+        assert!(lines.next().unwrap().is_ok());
+        assert!(matches!(lines.next(), Some(Err(Error::Read(_)))));
+        assert_eq!(lines.next(), None);
+
+        assert!(read_all(&mut &invalid[..]).is_err());
+        assert_eq!(read_all(&mut "1\n2".as_bytes()), Ok("1\n2".to_string()));
+    }
+}
@@ -0,0 +1,264 @@
+//! Opt-in instrumentation of the solvers with named counters and nested spans.
+//!
+//! Without the `trace` feature every function here compiles to nothing, so solvers may count in
+//! their hottest loops. With it, [`collect`] records what a closure counted and how long the
+//! spans it opened took, on the current thread.
+
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+/// Whether the crate was built with the `trace` feature, without it traces are always empty
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// Number of times a span was entered and the time spent in it, including nested spans
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpanStats {
+    pub calls: u64,
+    pub total: Duration,
+}
+
+/// Counters and spans recorded by [`collect`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub counters: BTreeMap<&'static str, u64>,
+    /// Spans keyed by the names of the spans they were nested in, followed by their own name
+    pub spans: BTreeMap<Vec<&'static str>, SpanStats>,
+}
+
+impl Trace {
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.spans.is_empty()
+    }
+
+    /// Time spent in the span at `path` outside of the spans nested in it
+    pub fn self_time(&self, path: &[&'static str]) -> Duration {
+        let nested = self
+            .spans
+            .iter()
+            .filter(|(other, _)| other.len() == path.len() + 1 && other.starts_with(path))
+            .map(|(_, stats)| stats.total)
+            .sum();
+
+        self.spans
+            .get(path)
+            .map_or(Duration::ZERO, |stats| stats.total.saturating_sub(nested))
+    }
+
+    /// The spans in the folded stack format read by `flamegraph.pl` and `inferno-flamegraph`,
+    /// one line per span weighted by its self time in microseconds
+    pub fn folded(&self) -> String {
+        self.spans
+            .keys()
+            .map(|path| format!("{} {}\n", path.join(";"), self.self_time(path).as_micros()))
+            .collect()
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, stats) in &self.spans {
+            let name = format!(
+                "{:indent$}{}",
+                "",
+                path.last().unwrap_or(&""),
+                indent = 2 * (path.len() - 1)
+            );
+            writeln!(
+                f,
+                "{:<32} {:>10} calls {:>12.2?}",
+                name, stats.calls, stats.total
+            )?;
+        }
+        for (name, count) in &self.counters {
+            writeln!(f, "{:<32} {:>10}", name, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds one to the counter `name`
+#[inline(always)]
+pub fn count(name: &'static str) {
+    count_by(name, 1);
+}
+
+/// Adds `n` to the counter `name`
+#[inline(always)]
+pub fn count_by(name: &'static str, n: u64) {
+    #[cfg(feature = "trace")]
+    recorder::with(|recorder| *recorder.trace.counters.entry(name).or_default() += n);
+    #[cfg(not(feature = "trace"))]
+    let _ = (name, n);
+}
+
+/// Enters the span `name`, which lasts until the returned guard is dropped
+#[inline(always)]
+pub fn span(name: &'static str) -> Span {
+    #[cfg(feature = "trace")]
+    return Span {
+        active: recorder::with(|recorder| recorder.enter(name)).is_some(),
+    };
+    #[cfg(not(feature = "trace"))]
+    {
+        let _ = name;
+        Span {}
+    }
+}
+
+/// Guard of a span entered by [`span`]
+#[must_use = "the span ends as soon as its guard is dropped"]
+pub struct Span {
+    #[cfg(feature = "trace")]
+    active: bool,
+}
+
+#[cfg(feature = "trace")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        if self.active {
+            recorder::with(recorder::Recorder::exit);
+        }
+    }
+}
+
+/// Runs `f`, recording what it counts and the spans it enters on the current thread
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+    #[cfg(feature = "trace")]
+    return recorder::collect(f);
+    #[cfg(not(feature = "trace"))]
+    (f(), Trace::default())
+}
+
+#[cfg(feature = "trace")]
+mod recorder {
+    use std::{cell::RefCell, time::Instant};
+
+    use super::Trace;
+
+    thread_local! {
+        static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Default)]
+    pub struct Recorder {
+        pub trace: Trace,
+        stack: Vec<(&'static str, Instant)>,
+    }
+
+    impl Recorder {
+        pub fn enter(&mut self, name: &'static str) {
+            self.stack.push((name, Instant::now()));
+        }
+
+        pub fn exit(&mut self) {
+            if let Some((name, start)) = self.stack.pop() {
+                let mut path = self.stack.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+                path.push(name);
+
+                let stats = self.trace.spans.entry(path).or_default();
+                stats.calls += 1;
+                stats.total += start.elapsed();
+            }
+        }
+    }
+
+    /// Calls `f` with the recorder of the current thread, if [`collect`] is running on it
+    pub fn with<T>(f: impl FnOnce(&mut Recorder) -> T) -> Option<T> {
+        RECORDER.with(|recorder| recorder.borrow_mut().as_mut().map(f))
+    }
+
+    pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+        let outer = RECORDER.with(|recorder| recorder.replace(Some(Recorder::default())));
+        let value = f();
+        let recorder = RECORDER.with(|recorder| recorder.replace(outer));
+
+        (
+            value,
+            recorder.map(|recorder| recorder.trace).unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod trace_test {
+    use std::time::Duration;
+
+    use crate::trace::{self, SpanStats, Trace};
+
+    fn stats(calls: u64, millis: u64) -> SpanStats {
+        SpanStats {
+            calls,
+            total: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_folded() {
+        let mut trace = Trace::default();
+        trace.spans.insert(vec!["part 2"], stats(1, 10));
+        trace.spans.insert(vec!["part 2", "game"], stats(20, 4));
+        trace.spans.insert(vec!["part 2", "score"], stats(1, 1));
+
+        assert_eq!(trace.self_time(&["part 2"]), Duration::from_millis(5));
+        assert_eq!(
+            trace.folded(),
+            "part 2 5000\npart 2;game 4000\npart 2;score 1000\n"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let mut trace = Trace::default();
+        trace.spans.insert(vec!["parse"], stats(1, 2));
+        trace.spans.insert(vec!["parse", "rules"], stats(3, 1));
+        trace.counters.insert("games", 42);
+
+        let report = trace.to_string();
+        let lines = report.lines().map(str::split_whitespace);
+        assert_eq!(
+            lines.map(Iterator::collect::<Vec<_>>).collect::<Vec<_>>(),
+            vec![
+                vec!["parse", "1", "calls", "2.00ms"],
+                vec!["rules", "3", "calls", "1.00ms"],
+                vec!["games", "42"],
+            ]
+        );
+        assert!(report.lines().nth(1).unwrap().starts_with("  rules"));
+    }
+
+    #[cfg(not(feature = "trace"))]
+    #[test]
+    fn test_collect_disabled() {
+        let (value, trace) = trace::collect(|| {
+            let _span = trace::span("outer");
+            trace::count("steps");
+            7
+        });
+
+        assert_eq!(value, 7);
+        assert!(trace.is_empty());
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn test_collect() {
+        let (value, trace) = trace::collect(|| {
+            let _outer = trace::span("outer");
+            for _ in 0..3 {
+                let _inner = trace::span("inner");
+                trace::count("steps");
+            }
+            trace::count_by("steps", 10);
+            7
+        });
+
+        assert_eq!(value, 7);
+        assert_eq!(trace.counters["steps"], 13);
+        assert_eq!(trace.spans[&vec!["outer"]].calls, 1);
+        assert_eq!(trace.spans[&vec!["outer", "inner"]].calls, 3);
+
+        // Nothing is recorded outside of `collect`
+        trace::count("steps");
+        assert!(trace::collect(|| ()).1.is_empty());
+    }
+}
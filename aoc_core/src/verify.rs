@@ -0,0 +1,211 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+use serde_json::{Map, Value};
+
+/// File formats answers can be stored in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Format of the file at `path`, JSON for a `.json` extension and TOML otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Format::Json,
+            _ => Format::Toml,
+        }
+    }
+}
+
+/// Known answers of each day and part.
+///
+/// Stored as one table per day containing one entry per part, e.g. `[day_01]` with `part_1 =
+/// "381699"` in TOML or `{"day_01": {"part_1": "381699"}}` in JSON. Answers may also be given as
+/// integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+/// Result of comparing an answer to the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn insert(&mut self, day: u8, part: u8, answer: impl Into<String>) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    pub fn remove(&mut self, day: u8, part: u8) -> Option<String> {
+        self.answers.remove(&(day, part))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Outcome {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+            },
+            None => Outcome::Missing,
+        }
+    }
+
+    /// Loads the answers at `path`, a missing file yields no answers at all
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let raw = match fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(err),
+        };
+
+        Answers::parse(&raw, Format::from_path(path)).map_err(|err| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err),
+            )
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.serialize(Format::from_path(path)))
+    }
+
+    pub fn parse(raw: &str, format: Format) -> Result<Self, String> {
+        let value = match format {
+            Format::Toml => toml::from_str::<Value>(raw).map_err(|err| err.to_string())?,
+            Format::Json => serde_json::from_str::<Value>(raw).map_err(|err| err.to_string())?,
+        };
+
+        let mut answers = Answers::default();
+        let days = value.as_object().ok_or("expected a table of days")?;
+
+        for (day_key, parts) in days {
+            let day = parse_key(day_key, "day_")?;
+            let parts = parts
+                .as_object()
+                .ok_or_else(|| format!("{}: expected a table of parts", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = parse_key(part_key, "part_")?;
+                let answer = match answer {
+                    Value::String(answer) => answer.clone(),
+                    Value::Number(answer) => answer.to_string(),
+                    _ => return Err(format!("{}.{}: expected an answer", day_key, part_key)),
+                };
+                answers.insert(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn serialize(&self, format: Format) -> String {
+        let mut days = Map::new();
+        for ((day, part), answer) in &self.answers {
+            days.entry(format!("day_{:02}", day))
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .unwrap()
+                .insert(format!("part_{}", part), Value::String(answer.clone()));
+        }
+
+        let value = Value::Object(days);
+        match format {
+            Format::Toml => toml::to_string(&value).unwrap(),
+            Format::Json => format!("{:#}\n", value),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => f.pad("pass"),
+            Outcome::Fail { .. } => f.pad("FAIL"),
+            Outcome::Missing => f.pad("missing"),
+        }
+    }
+}
+
+/// Parses keys like `day_07` or `part_2`, the prefix being optional
+fn parse_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .unwrap_or(key)
+        .parse()
+        .map_err(|_| format!("invalid key {:?}, expected e.g. \"{}1\"", key, prefix))
+}
+
+#[cfg(test)]
+mod verify_test {
+    use std::path::Path;
+
+    use crate::verify::{Answers, Format, Outcome};
+
+    #[test]
+    fn test_parse() {
+        let toml =
+            "[day_01]\npart_1 = \"381699\"\npart_2 = 111605670\n\n[day_13]\npart_1 = \"2165\"\n";
+        let json = r#"{"day_01": {"part_1": "381699", "part_2": 111605670}, "13": {"1": "2165"}}"#;
+
+        let answers = Answers::parse(toml, Format::Toml).unwrap();
+        assert_eq!(answers.get(1, 2), Some("111605670"));
+        assert_eq!(answers.get(13, 1), Some("2165"));
+        assert_eq!(answers.get(13, 2), None);
+        assert_eq!(Answers::parse(json, Format::Json).unwrap(), answers);
+
+        assert!(Answers::parse("[day_x]\npart_1 = \"1\"", Format::Toml).is_err());
+        assert!(Answers::parse(r#"{"day_01": {"part_1": true}}"#, Format::Json).is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut answers = Answers::default();
+        answers.insert(13, 1, "2165");
+        answers.insert(1, 1, "381699");
+
+        for format in [Format::Toml, Format::Json] {
+            let raw = answers.serialize(format);
+            assert_eq!(Answers::parse(&raw, format).unwrap(), answers);
+        }
+        assert!(answers.serialize(Format::Toml).starts_with("[day_01]"));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, "381699");
+
+        assert_eq!(answers.check(1, 1, "381699"), Outcome::Pass);
+        assert_eq!(
+            answers.check(1, 1, "42"),
+            Outcome::Fail {
+                expected: "381699".to_string()
+            }
+        );
+        assert_eq!(answers.check(1, 2, "42"), Outcome::Missing);
+
+        answers.remove(1, 1);
+        assert_eq!(answers.check(1, 1, "381699"), Outcome::Missing);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::from_path(Path::new("answers.json")), Format::Json);
+        assert_eq!(Format::from_path(Path::new("answers.toml")), Format::Toml);
+    }
+}
@@ -0,0 +1,181 @@
+//! Frames showing how a puzzle is solved, step by step.
+//!
+//! Days which support it render their state into [`Frame`]s and hand them to a [`Sink`], see
+//! [`Solution::visualise`](crate::Solution::visualise). The `aoc visualise` subcommand plays them
+//! back in the terminal or exports them as PNG or GIF.
+
+use std::{convert::TryFrom, fmt::Write};
+
+/// Colour of a pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(64, 64, 64);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const NAVY: Rgb = Rgb(7, 54, 66);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+}
+
+/// Image of the state of a puzzle at one point in time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Frame {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Creates a frame by calling `pixel` with the `x` and `y` of each pixel, row by row
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut pixel: impl FnMut(usize, usize) -> Rgb,
+    ) -> Self {
+        Frame {
+            width,
+            height,
+            pixels: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| pixel(x, y))
+                .collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All pixels, row by row
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<Rgb> {
+        self.index(x, y).map(|idx| self.pixels[idx])
+    }
+
+    /// Colours a pixel, pixels outside of the frame are ignored
+    pub fn set(&mut self, x: isize, y: isize, color: Rgb) {
+        if let Some(idx) = self.index(x, y) {
+            self.pixels[idx] = color;
+        }
+    }
+
+    /// Draws a straight line between both points, including them
+    pub fn line(&mut self, from: (isize, isize), to: (isize, isize), color: Rgb) {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let steps = dx.abs().max(dy.abs()).max(1);
+
+        for step in 0..=steps {
+            let x = from.0 + (dx * step + steps / 2 * dx.signum()) / steps;
+            let y = from.1 + (dy * step + steps / 2 * dy.signum()) / steps;
+            self.set(x, y, color);
+        }
+    }
+
+    /// Renders the frame for a terminal supporting 24-bit colours, two rows of pixels per line
+    pub fn ansi(&self) -> String {
+        let mut ansi = String::new();
+
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let Rgb(r, g, b) = self.pixels[y * self.width + x];
+                let _ = write!(ansi, "\x1b[38;2;{};{};{}m", r, g, b);
+                if y + 1 < self.height {
+                    let Rgb(r, g, b) = self.pixels[(y + 1) * self.width + x];
+                    let _ = write!(ansi, "\x1b[48;2;{};{};{}m", r, g, b);
+                }
+                ansi.push('▀');
+            }
+            ansi.push_str("\x1b[0m\n");
+        }
+
+        ansi
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+/// Receives the frames of a visualisation as they are rendered
+pub trait Sink {
+    fn frame(&mut self, frame: Frame);
+}
+
+impl Sink for Vec<Frame> {
+    fn frame(&mut self, frame: Frame) {
+        self.push(frame);
+    }
+}
+
+#[cfg(test)]
+mod visual_test {
+    use crate::visual::{Frame, Rgb};
+
+    #[test]
+    fn test_set() {
+        let mut frame = Frame::new(3, 2, Rgb::BLACK);
+        frame.set(2, 1, Rgb::RED);
+        frame.set(3, 1, Rgb::RED);
+        frame.set(-1, 0, Rgb::RED);
+
+        assert_eq!(frame.get(2, 1), Some(Rgb::RED));
+        assert_eq!(frame.get(3, 1), None);
+        assert_eq!(
+            frame
+                .pixels()
+                .iter()
+                .filter(|pixel| **pixel == Rgb::RED)
+                .count(),
+            1
+        );
+        assert_eq!(
+            frame,
+            Frame::from_fn(3, 2, |x, y| if (x, y) == (2, 1) {
+                Rgb::RED
+            } else {
+                Rgb::BLACK
+            })
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        let mut frame = Frame::new(5, 3, Rgb::BLACK);
+        frame.line((0, 0), (4, 2), Rgb::WHITE);
+
+        let lit = (0..3)
+            .flat_map(|y| (0..5).map(move |x| (x, y)))
+            .filter(|(x, y)| frame.get(*x, *y) == Some(Rgb::WHITE))
+            .collect::<Vec<_>>();
+        assert_eq!(lit, vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]);
+    }
+
+    #[test]
+    fn test_ansi() {
+        let frame = Frame::from_fn(1, 3, |_, y| Rgb(y as u8, 0, 0));
+        assert_eq!(
+            frame.ansi(),
+            "\x1b[38;2;0;0;0m\x1b[48;2;1;0;0m▀\x1b[0m\n\x1b[38;2;2;0;0m▀\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Registry of all days' solvers, shared by the `aoc` runner and the WebAssembly build.
+
+use std::io::BufRead;
+
+use aoc_core::{Puzzle, Report, Result, Solver};
+
+pub struct Day {
+    pub puzzle: &'static dyn Puzzle,
+}
+
+impl Day {
+    pub fn number(&self) -> u8 {
+        self.puzzle.day()
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=self.puzzle.parts()).collect()
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<Report> {
+        self.puzzle.solve(part, input)
+    }
+
+    pub fn solve_with(&self, part: u8, input: &str, params: &[(String, String)]) -> Result<Report> {
+        self.puzzle.solve_with(part, input, params)
+    }
+
+    /// Parameters of this day which bound the work of its solvers regardless of the input, such
+    /// as the number of turns or moves, set low enough to solve arbitrary inputs quickly
+    pub fn small_params(&self) -> Vec<(String, String)> {
+        SMALL_PARAMS
+            .iter()
+            .filter(|(day, _, _)| *day == self.number())
+            .map(|(_, name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    pub fn stream_with(
+        &self,
+        part: u8,
+        reader: &mut dyn BufRead,
+        params: &[(String, String)],
+    ) -> Result<Report> {
+        self.puzzle.stream(part, reader, params)
+    }
+}
+
+/// Values for [`Day::small_params`], the defaults of all other parameters are quick enough
+const SMALL_PARAMS: &[(u8, &str, &str)] = &[
+    (15, "part1_turn", "100"),
+    (15, "part2_turn", "1000"),
+    (17, "cycles", "2"),
+    (23, "part1_moves", "10"),
+    (23, "part2_cups", "100"),
+    (23, "part2_moves", "1000"),
+    (24, "days", "5"),
+    (25, "modulus", "10007"),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number() == number)
+}
+
+macro_rules! days {
+    ($($krate:ident::$solution:ident),* $(,)?) => {
+        &[$(Day {
+            puzzle: &Solver::<$krate::$solution>::new(),
+        }),*]
+    };
+}
+
+pub static DAYS: &[Day] = days![
+    day_01::Day01,
+    day_02::Day02,
+    day_03::Day03,
+    day_04::Day04,
+    day_05::Day05,
+    day_06::Day06,
+    day_07::Day07,
+    day_08::Day08,
+    day_09::Day09,
+    day_10::Day10,
+    day_11::Day11,
+    day_12::Day12,
+    day_13::Day13,
+    day_14::Day14,
+    day_15::Day15,
+    day_16::Day16,
+    day_17::Day17,
+    day_18::Day18,
+    day_19::Day19,
+    day_20::Day20,
+    day_21::Day21,
+    day_22::Day22,
+    day_23::Day23,
+    day_24::Day24,
+    day_25::Day25,
+];
+
+#[cfg(test)]
+mod days_test {
+    use std::path::Path;
+
+    use aoc_core::{examples, input::InputSource, Error};
+    use proptest::prelude::*;
+
+    use crate::{find, DAYS};
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        let numbers = DAYS.iter().map(|day| day.number()).collect::<Vec<_>>();
+        assert_eq!(numbers, (1..=25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).unwrap().puzzle.title(), "Handy Haversacks");
+        assert_eq!(find(25).unwrap().parts(), vec![1]);
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_run_day() {
+        let input = include_str!("../../inputs/day_07.txt");
+        assert_eq!(find(7).unwrap().solve(1, input).unwrap().answer, "259");
+        assert_eq!(find(7).unwrap().solve(2, input).unwrap().answer, "45018");
+
+        let input = include_str!("../../inputs/day_09.txt");
+        let report = find(9).unwrap().solve(2, input).unwrap();
+        assert_eq!(report.answer, "23463012");
+        assert!(report.details.unwrap().get("min").is_some());
+
+        let input = include_str!("../../inputs/day_25.txt");
+        assert!(find(25).unwrap().solve(2, input).is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let input = include_str!("../../inputs/day_09.txt");
+        let params = [("window".to_string(), "5".to_string())];
+        assert_ne!(
+            find(9)
+                .unwrap()
+                .solve_with(1, input, &params)
+                .unwrap()
+                .answer,
+            "177777905"
+        );
+        assert!(find(9).unwrap().solve_with(1, input, &params[..0]).is_ok());
+        assert!(find(7).unwrap().solve_with(1, input, &params).is_err());
+    }
+
+    #[test]
+    fn test_default_params() {
+        let dir = InputSource::Directory(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs").into());
+
+        // Setting every parameter to its default as shown by `list` changes nothing
+        for day in DAYS {
+            let params = day
+                .puzzle
+                .params()
+                .into_iter()
+                .map(|param| (param.name.to_string(), param.default))
+                .collect::<Vec<_>>();
+            let input = dir.read(day.number()).unwrap();
+
+            assert_eq!(
+                day.solve_with(1, &input, &params),
+                day.solve(1, &input),
+                "day {}",
+                day.number()
+            );
+        }
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        let dir = InputSource::Directory(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs").into());
+
+        for day in DAYS {
+            let input = dir.read(day.number()).unwrap();
+            let windows = format!("\u{feff}{}\r\n\r\n", input.replace('\n', " \r\n"));
+
+            assert_eq!(
+                day.solve(1, &windows).unwrap().answer,
+                day.solve(1, &input).unwrap().answer,
+                "day {}",
+                day.number()
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for day in DAYS {
+            for seed in 0..3 {
+                let generated = day.puzzle.generate(seed);
+                assert_eq!(day.puzzle.generate(seed), generated);
+                assert!(day.puzzle.validate(&generated.input).is_ok());
+
+                for part in day.parts() {
+                    if let Some(expected) = generated.answer(part) {
+                        let report = day.solve(part, &generated.input).unwrap();
+                        assert_eq!(
+                            report.answer,
+                            expected,
+                            "day {}, part {}, seed {}",
+                            day.number(),
+                            part,
+                            seed
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_parsers_never_panic(day in 1..=25u8, input in any::<String>()) {
+            let _ = find(day).unwrap().puzzle.validate(&input);
+        }
+
+        /// Inputs made of the characters puzzle inputs consist of get further into the parsers
+        /// than arbitrary unicode
+        #[test]
+        fn test_parsers_never_panic_on_puzzle_like_input(
+            day in 1..=25u8,
+            input in "([0-9a-z#.:,()+*|\"\\- ]{0,12}\r?\n{0,2}){0,12}",
+        ) {
+            let _ = find(day).unwrap().puzzle.validate(&input);
+        }
+
+        #[test]
+        fn test_solvers_never_panic(day in 1..=25u8, input in any::<String>()) {
+            let day = find(day).unwrap();
+            for part in day.parts() {
+                let _ = day.solve_with(part, &input, &day.small_params());
+            }
+        }
+
+        /// Beyond the parsers, inputs which parse are solved with the small parameters
+        #[test]
+        fn test_solvers_never_panic_on_puzzle_like_input(
+            day in 1..=25u8,
+            input in "([0-9a-z#.:,()+*|\"\\- ]{0,12}\r?\n{0,2}){0,12}",
+        ) {
+            let day = find(day).unwrap();
+            for part in day.parts() {
+                let _ = day.solve_with(part, &input, &day.small_params());
+            }
+        }
+
+        /// Generated inputs with a few characters replaced mostly still parse, which gets the
+        /// solvers to work on them
+        #[test]
+        fn test_solvers_never_panic_on_changed_inputs(
+            day in 1..=25u8,
+            seed in 0..4u64,
+            changes in prop::collection::vec(
+                (any::<prop::sample::Index>(), "[0-9a-z#.:,()+*|\"\\- \n]"),
+                1..4,
+            ),
+        ) {
+            let day = find(day).unwrap();
+            let mut input = day.puzzle.generate(seed).input.chars().collect::<Vec<_>>();
+            for (index, replacement) in changes {
+                let index = index.index(input.len());
+                input[index] = replacement.chars().next().unwrap();
+            }
+            let input = input.into_iter().collect::<String>();
+
+            for part in day.parts() {
+                let _ = day.solve_with(part, &input, &day.small_params());
+            }
+        }
+    }
+
+    #[test]
+    fn test_small_params() {
+        // The small parameters are declared by their day and accepted on its input
+        let dir = InputSource::Directory(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs").into());
+        for day in DAYS {
+            let params = day.small_params();
+            let declared = day.puzzle.params();
+            for (name, _) in &params {
+                assert!(declared.iter().any(|param| param.name == name), "{}", name);
+            }
+
+            let input = dir.read(day.number()).unwrap();
+            let report = day.solve_with(1, &input, &params);
+            assert!(
+                !matches!(report, Err(Error::InvalidParameter(_))),
+                "day {}: {:?}",
+                day.number(),
+                report
+            );
+        }
+        assert_eq!(find(7).unwrap().small_params(), vec![]);
+    }
+
+    #[test]
+    fn test_examples() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+
+        // Every answer of every fixture, see `aoc examples`
+        for day in DAYS {
+            for (number, example) in (1..).zip(examples::load(&dir, day.number()).unwrap()) {
+                for (part, answer) in &example.answers {
+                    let report = day.solve_with(*part, &example.input, &example.params);
+                    assert_eq!(
+                        report.map(|report| report.answer).as_ref(),
+                        Ok(answer),
+                        "day {}, example {}, part {}",
+                        day.number(),
+                        number,
+                        part
+                    );
+                }
+            }
+        }
+    }
+}
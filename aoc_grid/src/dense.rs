@@ -0,0 +1,404 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::{
+    normalize,
+    visual::{Frame, Rgb},
+    Error, Result,
+};
+
+use crate::Point;
+
+/// Rectangular grid storing every cell, row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid by calling `cell` for each point, row by row
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+            .map(&mut cell)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, mapping each character with `cell`.
+    ///
+    /// Leading and trailing whitespace, Windows line endings and blank lines are ignored, all rows
+    /// have to be of the same width.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Grid::parse_in(input, input, cell)
+    }
+
+    /// Like [`Grid::parse`], for a `section` which has to be a slice of `input` for error
+    /// reporting
+    pub fn parse_in(
+        input: &str,
+        section: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in normalize::lines(section) {
+            let mut row_width = 0;
+            for (idx, ch) in line.char_indices() {
+                let value = cell(ch).ok_or_else(|| {
+                    Error::parse_at(
+                        input,
+                        &line[idx..],
+                        format!("unexpected character {:?}", ch),
+                    )
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(Error::parse_at(
+                    input,
+                    line,
+                    format!("expected {} columns, found {}", width, row_width),
+                ));
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(Error::parse_at(input, section, "the grid is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as isize).contains(&point.x) && (0..self.height as isize).contains(&point.y)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(move |idx| &mut self.cells[idx])
+    }
+
+    /// All points of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as isize, y as isize)))
+    }
+
+    /// All cells together with their points, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// The orthogonally adjacent cells within the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The adjacent cells within the grid, including the diagonals
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(move |neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The cells seen when walking from `start` in `direction` until leaving the grid, excluding
+    /// `start` itself
+    pub fn ray(&self, start: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> {
+        (1..)
+            .map(move |steps| start + direction * steps)
+            .map_while(move |point| Some((point, self.get(point)?)))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Renders the grid with one pixel per cell, coloured by `color`
+    pub fn frame(&self, mut color: impl FnMut(&T) -> Rgb) -> Frame {
+        Frame::from_fn(self.width, self.height, |x, y| {
+            color(&self.cells[y * self.width + x])
+        })
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Rotates by 90° counterclockwise, the right column becoming the top row
+    pub fn rotate_left(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(self.width as isize - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates by 90° clockwise, the left column becoming the top row
+    pub fn rotate_right(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, self.height as isize - 1 - p.x)].clone()
+        })
+    }
+
+    /// Mirrors along the vertical axis, reversing each row
+    pub fn flip_horizontal(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(self.width as isize - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors along the horizontal axis, reversing each column
+    pub fn flip_vertical(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point::new(p.x, self.height as isize - 1 - p.y)].clone()
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// All eight combinations of rotations and flips: the four rotations to the left, followed
+    /// by the four rotations of the horizontally flipped grid
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        for start in [self.clone(), self.flip_horizontal()] {
+            let mut grid = start;
+            for _ in 0..4 {
+                let next = grid.rotate_left();
+                orientations.push(grid);
+                grid = next;
+            }
+        }
+        orientations
+    }
+
+    /// The rectangle of `width` by `height` cells starting at `origin`, which has to fit into the
+    /// grid
+    pub fn sub_grid(&self, origin: Point, width: usize, height: usize) -> Self {
+        Grid::from_fn(width, height, |p| self[origin + p].clone())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod dense_test {
+    use aoc_core::{visual::Rgb, Error};
+    use proptest::prelude::*;
+
+    use crate::{Grid, Point};
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("\u{feff}ab.\r\n  #cd \r\n\r\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 1)], 'c');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "ab.\n#cd\n");
+
+        let bits = |ch| match ch {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        assert_eq!(
+            Grid::parse("..#\n.x.", bits),
+            Err(Error::parse(2, 2, "unexpected character 'x'"))
+        );
+        assert_eq!(
+            Grid::parse("..#\n..", bits),
+            Err(Error::parse(2, 1, "expected 3 columns, found 2"))
+        );
+        assert_eq!(
+            Grid::parse("..#\r\n.#.\r\n\r\n#.", bits),
+            Err(Error::parse(4, 1, "expected 3 columns, found 2"))
+        );
+        assert!(Grid::parse("\n\n", bits).is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse("abc\ndef");
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = parse("abc\ndef\nghi");
+        let corner = grid.neighbours8(Point::ORIGIN).map(|(_, ch)| *ch);
+        assert_eq!(corner.collect::<String>(), "bed");
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+
+        let ray = grid.ray(Point::ORIGIN, Point::new(1, 1)).map(|(_, ch)| *ch);
+        assert_eq!(ray.collect::<String>(), "ei");
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = parse("ab\ncd\nef");
+        assert_eq!(grid.rotate_left().to_string(), "bdf\nace\n");
+        assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flip_vertical().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(orientations[4], grid.flip_horizontal());
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let grid = parse("abcd\nefgh\nijkl");
+        assert_eq!(
+            grid.sub_grid(Point::new(1, 1), 2, 2).to_string(),
+            "fg\njk\n"
+        );
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..\n");
+        assert_eq!(
+            grid.map(|ch| ch.to_ascii_uppercase()).row(0),
+            ['A', 'B', 'C', 'D']
+        );
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame =
+            parse(".#\n#.\n..").frame(|ch| if *ch == '#' { Rgb::WHITE } else { Rgb::BLACK });
+        assert_eq!((frame.width(), frame.height()), (2, 3));
+        assert_eq!(frame.get(1, 0), Some(Rgb::WHITE));
+        assert_eq!(frame.get(1, 1), Some(Rgb::BLACK));
+    }
+
+    fn grids() -> impl Strategy<Value = Grid<u8>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(any::<u8>(), width * height).prop_map(move |cells| {
+                Grid::from_fn(width, height, |p| {
+                    cells[p.y as usize * width + p.x as usize]
+                })
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_rotations_are_cyclic(grid in grids()) {
+            let rotated = (0..4).fold(grid.clone(), |grid, _| grid.rotate_left());
+            prop_assert_eq!(&rotated, &grid);
+            prop_assert_eq!(&grid.rotate_left().rotate_right(), &grid);
+        }
+
+        #[test]
+        fn test_flips_are_involutions(grid in grids()) {
+            prop_assert_eq!(&grid.flip_horizontal().flip_horizontal(), &grid);
+            prop_assert_eq!(&grid.flip_vertical().flip_vertical(), &grid);
+            prop_assert_eq!(&grid.transpose().transpose(), &grid);
+            prop_assert_eq!(grid.rotate_left().flip_vertical(), grid.transpose());
+        }
+
+        #[test]
+        fn test_display_round_trips(grid in grids()) {
+            let digits = grid.map(|cell| cell % 10);
+            let parsed = Grid::parse(&digits.to_string(), |ch| ch.to_digit(10).map(|d| d as u8));
+            prop_assert_eq!(parsed, Ok(digits));
+        }
+    }
+}
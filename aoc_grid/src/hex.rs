@@ -0,0 +1,80 @@
+use std::ops::{Add, AddAssign};
+
+/// Coordinate on a hexagonal grid of pointy-topped hexagons, in axial coordinates.
+///
+/// `q` grows towards the east and `r` towards the south east, the third cube coordinate being
+/// `-q - r`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const EAST: Hex = Hex::new(1, 0);
+    pub const SOUTH_EAST: Hex = Hex::new(0, 1);
+    pub const SOUTH_WEST: Hex = Hex::new(-1, 1);
+    pub const WEST: Hex = Hex::new(-1, 0);
+    pub const NORTH_WEST: Hex = Hex::new(0, -1);
+    pub const NORTH_EAST: Hex = Hex::new(1, -1);
+
+    /// The six directions, clockwise starting east
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex::EAST,
+        Hex::SOUTH_EAST,
+        Hex::SOUTH_WEST,
+        Hex::WEST,
+        Hex::NORTH_WEST,
+        Hex::NORTH_EAST,
+    ];
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        Hex { q, r }
+    }
+
+    /// The six adjacent hexagons
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        IntoIterator::into_iter(Hex::DIRECTIONS).map(move |dir| self + dir)
+    }
+
+    /// Number of steps between both hexagons
+    pub fn distance(self, other: Hex) -> usize {
+        let (dq, dr) = (self.q - other.q, self.r - other.r);
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(test)]
+mod hex_test {
+    use crate::Hex;
+
+    #[test]
+    fn test_directions() {
+        // Walking around a hexagon leads back to the start
+        let end = Hex::DIRECTIONS
+            .iter()
+            .fold(Hex::ORIGIN, |hex, dir| hex + *dir);
+        assert_eq!(end, Hex::ORIGIN);
+        assert_eq!(Hex::EAST + Hex::SOUTH_WEST, Hex::SOUTH_EAST);
+        assert!(Hex::ORIGIN
+            .neighbours()
+            .all(|n| n.distance(Hex::ORIGIN) == 1));
+        assert_eq!(Hex::new(2, -1).distance(Hex::new(-1, 1)), 3);
+    }
+}
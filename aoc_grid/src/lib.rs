@@ -0,0 +1,17 @@
+//! Grids and coordinates shared by the days working on maps, images or cellular automata.
+//!
+//! [`Grid`] stores a dense rectangle of cells, [`SparseGrid`] only the cells which are set and can
+//! grow in any direction. Coordinates are typed: [`Point`] for square grids, [`Hex`] for hexagonal
+//! ones and [`PointN`] for any number of dimensions.
+
+mod dense;
+mod hex;
+mod point;
+mod point_n;
+mod sparse;
+
+pub use dense::Grid;
+pub use hex::Hex;
+pub use point::Point;
+pub use point_n::PointN;
+pub use sparse::SparseGrid;
@@ -0,0 +1,109 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// Coordinate on a square grid, `y` growing downwards like the rows of a text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const NORTH: Point = Point::new(0, -1);
+    pub const EAST: Point = Point::new(1, 0);
+    pub const SOUTH: Point = Point::new(0, 1);
+    pub const WEST: Point = Point::new(-1, 0);
+
+    /// The four orthogonal directions, clockwise starting north
+    pub const ORTHOGONAL: [Point; 4] = [Point::NORTH, Point::EAST, Point::SOUTH, Point::WEST];
+
+    /// All eight directions including the diagonals, clockwise starting north
+    pub const ADJACENT: [Point; 8] = [
+        Point::NORTH,
+        Point::new(1, -1),
+        Point::EAST,
+        Point::new(1, 1),
+        Point::SOUTH,
+        Point::new(-1, 1),
+        Point::WEST,
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// The four orthogonally adjacent points
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        IntoIterator::into_iter(Point::ORTHOGONAL).map(move |dir| self + dir)
+    }
+
+    /// The eight adjacent points including the diagonals
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        IntoIterator::into_iter(Point::ADJACENT).map(move |dir| self + dir)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[cfg(test)]
+mod point_test {
+    use crate::Point;
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point::new(2, 3);
+        assert_eq!(
+            point.neighbours4().collect::<Vec<_>>(),
+            vec![
+                Point::new(2, 2),
+                Point::new(3, 3),
+                Point::new(2, 4),
+                Point::new(1, 3)
+            ]
+        );
+        assert_eq!(point.neighbours8().count(), 8);
+        assert!(point.neighbours8().all(|n| n != point));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point::new(1, 2) + Point::EAST * 3 - Point::SOUTH;
+        assert_eq!(point, Point::new(4, 1));
+        point += Point::NORTH;
+        assert_eq!(point.manhattan_distance(Point::ORIGIN), 4);
+    }
+}
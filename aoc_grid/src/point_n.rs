@@ -0,0 +1,69 @@
+use std::ops::Add;
+
+use crate::Point;
+
+/// Coordinate in `N` dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PointN<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> PointN<N> {
+    /// Embeds a point of a square grid, all further coordinates being 0. `N` must be at least 2.
+    pub fn from_2d(point: Point) -> Self {
+        assert!(N >= 2, "a {}-dimensional point cannot hold x and y", N);
+        let mut coords = [0; N];
+        coords[0] = point.x;
+        coords[1] = point.y;
+        PointN(coords)
+    }
+
+    /// All `3^N - 1` points which differ by at most one in every coordinate
+    pub fn neighbours(self) -> impl Iterator<Item = PointN<N>> {
+        (0..3usize.pow(N as u32))
+            .map(move |mut idx| {
+                let mut coords = self.0;
+                for coord in coords.iter_mut() {
+                    *coord += (idx % 3) as isize - 1;
+                    idx /= 3;
+                }
+                PointN(coords)
+            })
+            .filter(move |neighbour| *neighbour != self)
+    }
+}
+
+impl<const N: usize> Default for PointN<N> {
+    fn default() -> Self {
+        PointN([0; N])
+    }
+}
+
+impl<const N: usize> Add for PointN<N> {
+    type Output = PointN<N>;
+
+    fn add(mut self, other: PointN<N>) -> PointN<N> {
+        for (coord, other) in self.0.iter_mut().zip(other.0) {
+            *coord += other;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod point_n_test {
+    use std::collections::HashSet;
+
+    use crate::{Point, PointN};
+
+    #[test]
+    fn test_neighbours() {
+        let point = PointN::<3>::from_2d(Point::new(1, 2));
+        assert_eq!(point, PointN([1, 2, 0]));
+
+        let neighbours = point.neighbours().collect::<HashSet<_>>();
+        assert_eq!(neighbours.len(), 26);
+        assert!(neighbours.contains(&PointN([0, 3, -1])));
+        assert!(!neighbours.contains(&point));
+
+        assert_eq!(PointN::<4>::default().neighbours().count(), 80);
+    }
+}
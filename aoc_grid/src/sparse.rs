@@ -0,0 +1,179 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+use crate::{Grid, Point};
+
+/// Unbounded grid storing only the cells which are set, keyed by any kind of coordinate.
+///
+/// With `()` cells it acts as a set of active points, which [`SparseGrid::step`] evolves like a
+/// cellular automaton.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<P, T = ()> {
+    cells: HashMap<P, T>,
+}
+
+impl<P: Copy + Eq + Hash, T> SparseGrid<P, T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: P) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn insert(&mut self, point: P, cell: T) -> Option<T> {
+        self.cells.insert(point, cell)
+    }
+
+    pub fn remove(&mut self, point: P) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// All set points in arbitrary order
+    pub fn points(&self) -> impl Iterator<Item = P> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+}
+
+impl<T> SparseGrid<Point, T> {
+    /// Keeps the cells of a dense grid for which `keep` holds, at the same points
+    pub fn from_grid<U>(grid: &Grid<U>, mut keep: impl FnMut(&U) -> Option<T>) -> Self {
+        SparseGrid {
+            cells: grid
+                .iter()
+                .filter_map(|(point, cell)| Some((point, keep(cell)?)))
+                .collect(),
+        }
+    }
+
+    /// Smallest and largest coordinates of any set point, `None` if the grid is empty
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+}
+
+impl<P: Copy + Eq + Hash> SparseGrid<P> {
+    pub fn from_points(points: impl IntoIterator<Item = P>) -> Self {
+        SparseGrid {
+            cells: points.into_iter().map(|point| (point, ())).collect(),
+        }
+    }
+
+    /// Sets `point` if it is unset and unsets it otherwise
+    pub fn toggle(&mut self, point: P) {
+        match self.cells.entry(point) {
+            Entry::Occupied(entry) => {
+                entry.remove();
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(());
+            }
+        }
+    }
+
+    /// Runs one generation of a cellular automaton.
+    ///
+    /// A point is set in the next generation if `rule` holds for whether it is set now and its
+    /// number of set `neighbours`. Only set points and their neighbours are considered, so unset
+    /// points without set neighbours stay unset.
+    pub fn step<I>(
+        &self,
+        neighbours: impl Fn(P) -> I,
+        rule: impl Fn(bool, usize) -> bool,
+    ) -> SparseGrid<P>
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let mut counts = HashMap::<P, usize>::new();
+        for point in self.points() {
+            counts.entry(point).or_default();
+            for neighbour in neighbours(point) {
+                *counts.entry(neighbour).or_default() += 1;
+            }
+        }
+
+        SparseGrid::from_points(
+            counts
+                .into_iter()
+                .filter(|(point, count)| rule(self.contains(*point), *count))
+                .map(|(point, _)| point),
+        )
+    }
+}
+
+impl<P: Eq + Hash, T: PartialEq> PartialEq for SparseGrid<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<P: Eq + Hash, T: Eq> Eq for SparseGrid<P, T> {}
+
+impl<P: Copy + Eq + Hash, T> Default for SparseGrid<P, T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+#[cfg(test)]
+mod sparse_test {
+    use crate::{Grid, Point, SparseGrid};
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::parse(".#.\n..#\n###", Some).unwrap();
+        let active = SparseGrid::from_grid(&grid, |ch| if *ch == '#' { Some(()) } else { None });
+
+        assert_eq!(active.len(), 5);
+        assert!(active.contains(Point::new(2, 1)));
+        assert_eq!(active.bounds(), Some((Point::new(0, 0), Point::new(2, 2))));
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut grid = SparseGrid::new();
+        grid.toggle(Point::ORIGIN);
+        grid.toggle(Point::EAST);
+        grid.toggle(Point::ORIGIN);
+        assert_eq!(grid.points().collect::<Vec<_>>(), vec![Point::EAST]);
+    }
+
+    #[test]
+    fn test_step() {
+        // A blinker oscillates between a horizontal and a vertical line
+        let horizontal = SparseGrid::from_points((-1..=1).map(|x| Point::new(x, 0)));
+        let vertical = SparseGrid::from_points((-1..=1).map(|y| Point::new(0, y)));
+        let rule = |active, count| count == 3 || (active && count == 2);
+
+        assert_eq!(horizontal.step(Point::neighbours8, rule), vertical);
+        assert_eq!(vertical.step(Point::neighbours8, rule), horizontal);
+    }
+}
@@ -0,0 +1,98 @@
+//! WebAssembly build of all days' solvers with an API callable from JavaScript.
+//!
+//! Built for `wasm32-unknown-unknown` and bound with `wasm-bindgen`, the module exports
+//! `solve(day, part, input)` which returns the answer as a string or throws an `Error` with the
+//! message of what went wrong, e.g. where the input failed to parse.
+
+use aoc_core::{Error, Result};
+use wasm_bindgen::prelude::*;
+
+/// Parses `input` and solves `part` of `day`, giving the answer as it would be submitted
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String> {
+    let puzzle = aoc_days::find(day)
+        .ok_or_else(|| Error::invalid_parameter(format!("day {} is not available", day)))?;
+    puzzle.solve(part, input).map(|report| report.answer)
+}
+
+/// [`solve`] as exported to JavaScript
+#[wasm_bindgen(js_name = solve)]
+pub fn js_solve(day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    solve(day, part, input).map_err(|err| JsError::new(&err.to_string()))
+}
+
+#[cfg(test)]
+mod aoc_wasm_test {
+    use aoc_core::{
+        verify::{Answers, Format},
+        Error,
+    };
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::solve;
+
+    /// Embedded as a wasm runtime has no file system to read the inputs from
+    const INPUTS: [&str; 25] = [
+        include_str!("../../inputs/day_01.txt"),
+        include_str!("../../inputs/day_02.txt"),
+        include_str!("../../inputs/day_03.txt"),
+        include_str!("../../inputs/day_04.txt"),
+        include_str!("../../inputs/day_05.txt"),
+        include_str!("../../inputs/day_06.txt"),
+        include_str!("../../inputs/day_07.txt"),
+        include_str!("../../inputs/day_08.txt"),
+        include_str!("../../inputs/day_09.txt"),
+        include_str!("../../inputs/day_10.txt"),
+        include_str!("../../inputs/day_11.txt"),
+        include_str!("../../inputs/day_12.txt"),
+        include_str!("../../inputs/day_13.txt"),
+        include_str!("../../inputs/day_14.txt"),
+        include_str!("../../inputs/day_15.txt"),
+        include_str!("../../inputs/day_16.txt"),
+        include_str!("../../inputs/day_17.txt"),
+        include_str!("../../inputs/day_18.txt"),
+        include_str!("../../inputs/day_19.txt"),
+        include_str!("../../inputs/day_20.txt"),
+        include_str!("../../inputs/day_21.txt"),
+        include_str!("../../inputs/day_22.txt"),
+        include_str!("../../inputs/day_23.txt"),
+        include_str!("../../inputs/day_24.txt"),
+        include_str!("../../inputs/day_25.txt"),
+    ];
+
+    #[wasm_bindgen_test]
+    fn test_answers() {
+        let answers =
+            Answers::parse(include_str!("../../inputs/answers.toml"), Format::Toml).unwrap();
+
+        // Answers exceeding 32 bits catch solvers relying on a 64-bit `usize`
+        for (day, input) in (1..).zip(INPUTS) {
+            for part in 1..=2 {
+                if let Some(expected) = answers.get(day, part) {
+                    assert_eq!(
+                        solve(day, part, input).as_deref(),
+                        Ok(expected),
+                        "day {}, part {}",
+                        day,
+                        part
+                    );
+                }
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_errors() {
+        assert_eq!(
+            solve(26, 1, "").unwrap_err(),
+            Error::invalid_parameter("day 26 is not available")
+        );
+        assert_eq!(
+            solve(25, 2, INPUTS[24]).unwrap_err(),
+            Error::invalid_parameter("day 25 has no part 2")
+        );
+        assert_eq!(
+            solve(1, 1, "1721\n97x").unwrap_err(),
+            Error::parse(2, 1, "\"97x\": invalid digit found in string")
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Compares the sorted search of `k_sum` with the `HashSet` lookups day 1 used to solve with, on
+//! reports shaped like the puzzle's but with up to a million 64-bit entries.
+//!
+//! Run with `cargo bench -p day_01`. Both sides are timed including building the structure they
+//! search, the hash set only on reports small enough for its quadratic search of three entries.
+
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    bench::Stats,
+    generate::{Rng, SeedableRng, SliceRandom, StdRng},
+};
+use day_01::Expenses;
+
+const TARGET: i64 = 1 << 40;
+const SIZES: [usize; 4] = [1_000, 10_000, 100_000, 1_000_000];
+/// Largest report the hash set is timed on
+const BASELINE_SIZE: usize = 10_000;
+const ITERATIONS: usize = 5;
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(2020);
+
+    for size in SIZES {
+        let report = generate_report(&mut rng, size);
+
+        for k in 2..=3 {
+            let stats = measure(|| {
+                let expenses = report.iter().copied().collect::<Expenses>();
+                expenses.find_sum(k, TARGET).map(|values| values.len())
+            });
+            print_stats("k_sum", size, k, stats);
+
+            if size <= BASELINE_SIZE {
+                let stats = measure(|| {
+                    let set = report.iter().copied().collect::<HashSet<_>>();
+                    if k == 2 {
+                        find_pair(&set, TARGET).map(|_| 2)
+                    } else {
+                        find_triple(&set, TARGET).map(|_| 3)
+                    }
+                });
+                print_stats("HashSet", size, k, stats);
+            }
+        }
+    }
+}
+
+/// Entries above half the target, any two of which exceed it, among which a pair and a triple
+/// summing up to the target are hidden
+fn generate_report(rng: &mut StdRng, size: usize) -> Vec<i64> {
+    let pair = rng.gen_range(1..TARGET / 4);
+    let (a, b) = (rng.gen_range(1..TARGET / 4), rng.gen_range(1..TARGET / 4));
+
+    let mut report = vec![pair, TARGET - pair, a, b, TARGET - a - b];
+    report.extend((5..size).map(|_| rng.gen_range(TARGET / 2 + 1..TARGET)));
+    report.shuffle(rng);
+    report
+}
+
+/// Times `search`, which has to find the entries, over several iterations
+fn measure(mut search: impl FnMut() -> Option<usize>) -> Stats {
+    let samples = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            assert!(search().is_some(), "no entries found");
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    Stats::from_samples(&samples).unwrap()
+}
+
+fn print_stats(name: &str, size: usize, k: usize, stats: Stats) {
+    println!(
+        "{:<8} {:>9} entries  k = {}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+        name, size, k, stats.min, stats.median, stats.max
+    );
+}
+
+/// Day 1's first solution for two entries, which cannot tell apart entries of the same value
+fn find_pair(input: &HashSet<i64>, target: i64) -> Option<(i64, i64)> {
+    input
+        .iter()
+        .map(|i| (*i, target - *i))
+        .find(|(_, complementary)| *complementary >= 0 && input.contains(complementary))
+}
+
+/// Day 1's first solution for three entries, trying all pairs for the first two
+fn find_triple(input: &HashSet<i64>, target: i64) -> Option<(i64, i64, i64)> {
+    input.iter().find_map(|i| {
+        input
+            .iter()
+            .map(|k| (*i, *k, target - *i - *k))
+            .find(|(_, _, last)| input.contains(last))
+    })
+}
@@ -0,0 +1,221 @@
+//! Search for `k` entries of a sorted report summing up to a target, fast enough for reports of
+//! millions of 64-bit entries.
+//!
+//! One entry is found by binary search, two and three with two pointers closing in from both ends
+//! of the report, and more by meeting in the middle: all combinations of the first half of the
+//! entries are matched against the sorted sums of the second half. Sums are taken as `i128`, which
+//! no sum of 64-bit entries can overflow.
+//!
+//! Entries are positions in the report, so equal values are distinct entries. All searches return
+//! the lexicographically smallest values, the first combination [`Expenses::combinations`] yields.
+//!
+//! [`Expenses::combinations`]: crate::Expenses::combinations
+
+use aoc_core::trace;
+
+/// Finds `k` entries of `sorted`, which has to be sorted ascending, summing up to `target`
+pub fn find(sorted: &[i64], k: usize, target: i128) -> Option<Vec<i64>> {
+    match k {
+        0 => None,
+        1 => find_one(sorted, target).map(|idx| vec![sorted[idx]]),
+        2 => find_two(sorted, target).map(|(a, b)| vec![sorted[a], sorted[b]]),
+        3 => find_three(sorted, target),
+        _ => meet_in_the_middle(sorted, k, target),
+    }
+}
+
+fn find_one(sorted: &[i64], target: i128) -> Option<usize> {
+    trace::count("lookups");
+    let idx = sorted.partition_point(|entry| i128::from(*entry) < target);
+    (sorted.get(idx).map(|entry| i128::from(*entry)) == Some(target)).then_some(idx)
+}
+
+/// Positions of two entries summing up to `target`.
+///
+/// Moving the lower pointer up raises the sum, moving the upper one down lowers it, so the pair
+/// with the smallest first entry is the first one met.
+fn find_two(sorted: &[i64], target: i128) -> Option<(usize, usize)> {
+    let (mut lo, mut hi) = (0, sorted.len().checked_sub(1)?);
+
+    while lo < hi {
+        trace::count("pointer steps");
+        let sum = i128::from(sorted[lo]) + i128::from(sorted[hi]);
+        if sum < target {
+            lo += 1;
+        } else if sum > target {
+            hi -= 1;
+        } else {
+            return Some((lo, hi));
+        }
+    }
+
+    None
+}
+
+/// Three entries summing up to `target`, the first of which is each distinct value in turn
+fn find_three(sorted: &[i64], target: i128) -> Option<Vec<i64>> {
+    let largest = i128::from(*sorted.last()?);
+
+    for (idx, first) in sorted.iter().enumerate() {
+        let first = i128::from(*first);
+        if idx > 0 && i128::from(sorted[idx - 1]) == first {
+            continue;
+        }
+        // The other two entries are at least as large as the first and at most the largest one
+        if 3 * first > target {
+            break;
+        }
+        if first + 2 * largest < target {
+            continue;
+        }
+
+        let rest = &sorted[idx + 1..];
+        if let Some((a, b)) = find_two(rest, target - first) {
+            return Some(vec![sorted[idx], rest[a], rest[b]]);
+        }
+    }
+
+    None
+}
+
+/// `k` entries summing up to `target`, the first `k / 2` of which are matched against the sums of
+/// the rest, which all lie further up in the report.
+///
+/// Combinations stop growing as soon as their entries cannot stay below the target anymore, which
+/// on reports mostly made of large entries leaves few to look at.
+fn meet_in_the_middle(sorted: &[i64], k: usize, target: i128) -> Option<Vec<i64>> {
+    let (first, second) = (k / 2, k - k / 2);
+    if sorted.len() < k {
+        return None;
+    }
+
+    // The first half takes at least the smallest entries, which bounds the sums of the second
+    let smallest = sorted[..first]
+        .iter()
+        .map(|entry| i128::from(*entry))
+        .sum::<i128>();
+    let mut sums = Vec::new();
+    combinations(
+        sorted,
+        (second, 0),
+        first,
+        target - smallest,
+        &mut |indices, sum| {
+            sums.push((sum, indices[0], indices.to_vec()));
+        },
+    );
+    sums.sort_unstable();
+
+    let mut best: Option<Vec<i64>> = None;
+    combinations(sorted, (first, second), 0, target, &mut |indices, sum| {
+        let last = indices[first - 1];
+        let start = sums.partition_point(|(other, _, _)| *other < target - sum);
+        let matching = sums[start..]
+            .iter()
+            .take_while(|(other, _, _)| *other == target - sum)
+            .filter(|(_, from, _)| *from > last);
+
+        for (_, _, rest) in matching {
+            trace::count("lookups");
+            let values = indices
+                .iter()
+                .chain(rest)
+                .map(|idx| sorted[*idx])
+                .collect::<Vec<_>>();
+            if best.as_ref().is_none_or(|best| values < *best) {
+                best = Some(values);
+            }
+        }
+    });
+
+    best
+}
+
+/// Hands every combination of `len` positions of `sorted` from `from` on to `f`, together with
+/// its sum, skipping those which cannot keep the sum within `limit` once `following` more entries
+/// further up are added to them
+fn combinations(
+    sorted: &[i64],
+    (len, following): (usize, usize),
+    from: usize,
+    limit: i128,
+    f: &mut dyn FnMut(&[usize], i128),
+) {
+    fn extend(
+        sorted: &[i64],
+        (len, following): (usize, usize),
+        from: usize,
+        limit: i128,
+        (indices, sum): (&mut Vec<usize>, i128),
+        f: &mut dyn FnMut(&[usize], i128),
+    ) {
+        if indices.len() == len {
+            f(indices, sum);
+            return;
+        }
+
+        let missing = (len - indices.len() + following) as i128;
+        for idx in from..sorted.len() {
+            // Later entries are no smaller, so neither are the sums they lead to
+            let entry = i128::from(sorted[idx]);
+            if sum + entry * missing > limit {
+                break;
+            }
+            trace::count("combinations");
+            indices.push(idx);
+            extend(
+                sorted,
+                (len, following),
+                idx + 1,
+                limit,
+                (&mut *indices, sum + entry),
+                f,
+            );
+            indices.pop();
+        }
+    }
+
+    let mut indices = Vec::with_capacity(len);
+    extend(sorted, (len, following), from, limit, (&mut indices, 0), f);
+}
+
+#[cfg(test)]
+mod k_sum_test {
+    use crate::k_sum::find;
+
+    #[test]
+    fn test_find() {
+        let sorted = [-5, 0, 299, 366, 675, 979, 1010, 1010, 1456, 1721];
+
+        assert_eq!(find(&sorted, 1, 675), Some(vec![675]));
+        assert_eq!(find(&sorted, 1, 676), None);
+        assert_eq!(find(&sorted, 2, 2020), Some(vec![299, 1721]));
+        assert_eq!(find(&sorted, 2, 2020 + 1721), None);
+        assert_eq!(find(&sorted, 3, 2020), Some(vec![0, 299, 1721]));
+        assert_eq!(find(&sorted, 4, 3030), Some(vec![0, 299, 1010, 1721]));
+        assert_eq!(
+            find(&sorted, 5, 5130),
+            Some(vec![299, 675, 979, 1456, 1721])
+        );
+        assert_eq!(find(&sorted, 10, 7511), Some(sorted.to_vec()));
+        assert_eq!(find(&sorted, 11, 7511), None);
+        assert_eq!(find(&sorted, 0, 0), None);
+        assert_eq!(find(&[], 2, 0), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let sorted = [i64::MIN, -1, 1, i64::MAX - 1, i64::MAX];
+        let (min, max) = (i128::from(i64::MIN), i128::from(i64::MAX));
+
+        assert_eq!(
+            find(&sorted, 2, 2 * max - 1),
+            Some(vec![i64::MAX - 1, i64::MAX])
+        );
+        assert_eq!(find(&sorted, 2, 2 * max), None);
+        assert_eq!(find(&sorted, 2, min + max), Some(vec![i64::MIN, i64::MAX]));
+        assert_eq!(find(&sorted, 3, -1), Some(vec![i64::MIN, 1, i64::MAX - 1]));
+        assert_eq!(find(&sorted, 4, -1), Some(vec![i64::MIN, -1, 1, i64::MAX]));
+        assert_eq!(find(&sorted, 5, min + 2 * max - 1), Some(sorted.to_vec()));
+    }
+}
@@ -0,0 +1,692 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    io::BufRead,
+    iter::{FromIterator, FusedIterator},
+};
+
+use aoc_core::{
+    error,
+    generate::{retry, Generated, Rng, SliceRandom, StdRng},
+    normalize, params, stream, trace, Answer, Error, Result, Solution,
+};
+use serde_json::{json, Value};
+
+pub mod k_sum;
+
+pub struct Day01;
+
+params! {
+    pub struct Params {
+        /// Sum the entries of the expense report have to add up to
+        target: i64 = 2020,
+        /// Number of entries part 1 looks for
+        part1_entries: usize = 2,
+        /// Number of entries part 2 looks for
+        part2_entries: usize = 3,
+        /// Whether to list every combination summing up to the target in the details instead of
+        /// only the first, which takes long on large reports
+        candidates: bool = false,
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    type Input<'a> = Expenses;
+    type Params = Params;
+    type Output1 = Entries;
+    type Output2 = Entries;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        find_entries(input, params.part1_entries, params)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        find_entries(input, params.part2_entries, params)
+    }
+
+    /// All combinations are looked for, so all entries are kept, but not the text they were read
+    /// from
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        let input = stream_entries(reader).collect::<Result<_>>()?;
+        Self::part1(&input, params)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        let input = stream_entries(reader).collect::<Result<_>>()?;
+        Self::part2(&input, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Entries of the expense report summing up to the target, the answer is the product of the first
+/// combination of them
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entries {
+    /// Combinations summing up to the target in the order of [`Combinations`], only the first one
+    /// unless all were asked for, never empty
+    candidates: Vec<Combination>,
+    product: i128,
+}
+
+impl Entries {
+    /// Values of the first combination, in ascending order
+    pub fn entries(&self) -> &[i64] {
+        &self.candidates[0].values
+    }
+
+    pub fn candidates(&self) -> &[Combination] {
+        &self.candidates
+    }
+
+    pub fn product(&self) -> i128 {
+        self.product
+    }
+}
+
+impl fmt::Display for Entries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.product())
+    }
+}
+
+impl Answer for Entries {
+    fn details(&self) -> Option<Value> {
+        let candidates = self
+            .candidates
+            .iter()
+            .map(|candidate| json!({ "entries": candidate.values, "ways": candidate.ways }))
+            .collect::<Vec<_>>();
+        Some(json!({ "entries": self.entries(), "candidates": candidates }))
+    }
+}
+
+/// Entries of an expense report, which may contain the same value several times
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expenses {
+    /// All entries in ascending order
+    entries: Vec<i64>,
+    /// Distinct values in ascending order, each with the number of entries holding it
+    values: Vec<(i64, usize)>,
+}
+
+impl Expenses {
+    /// Number of entries holding `value`
+    pub fn count(&self, value: i64) -> usize {
+        self.values
+            .binary_search_by_key(&value, |(value, _)| *value)
+            .map_or(0, |idx| self.values[idx].1)
+    }
+
+    /// Finds `k` entries summing up to `target`, the first of [`combinations`](Self::combinations)
+    /// but without going through the ones before it, see [`k_sum`]
+    pub fn find_sum(&self, k: usize, target: i64) -> Option<Vec<i64>> {
+        k_sum::find(&self.entries, k, i128::from(target))
+    }
+
+    /// Number of ways to pick entries with `values`, which are ascending, from the report
+    fn ways(&self, values: &[i64]) -> u64 {
+        values.chunk_by(|a, b| a == b).fold(1, |ways: u64, run| {
+            binomial(self.count(run[0]), run.len()).saturating_mul(ways)
+        })
+    }
+
+    /// Every combination of `k` entries summing up to `target`.
+    ///
+    /// The entries are distinct lines of the report, so a value is only used as often as it
+    /// occurs. Combinations of the same values are yielded once, with the number of ways to pick
+    /// their entries from the report. They come in lexicographic order of their values, which are
+    /// in ascending order themselves. There are no combinations of zero entries.
+    pub fn combinations(&self, k: usize, target: i64) -> Combinations<'_> {
+        Combinations {
+            values: &self.values,
+            k,
+            target: i128::from(target),
+            prefix: Vec::with_capacity(k),
+            state: if k == 0 { State::Done } else { State::Start },
+        }
+    }
+}
+
+impl FromIterator<i64> for Expenses {
+    fn from_iter<I: IntoIterator<Item = i64>>(entries: I) -> Self {
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_unstable();
+
+        let mut values = Vec::<(i64, usize)>::new();
+        for entry in &entries {
+            match values.last_mut() {
+                Some((value, count)) if value == entry => *count += 1,
+                _ => values.push((*entry, 1)),
+            }
+        }
+        Expenses { entries, values }
+    }
+}
+
+/// Values of entries summing up to the target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    /// In ascending order
+    pub values: Vec<i64>,
+    /// Number of ways to pick entries with these values from the report, more than one if a value
+    /// occurs more often than it is used
+    pub ways: u64,
+}
+
+/// Iterator over the combinations of entries summing up to a target, see
+/// [`Expenses::combinations`]
+pub struct Combinations<'a> {
+    values: &'a [(i64, usize)],
+    k: usize,
+    target: i128,
+    /// Indices into `values` of all but the last value of the current combination, ascending
+    prefix: Vec<usize>,
+    state: State,
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Start,
+    Searching,
+    Done,
+}
+
+impl Combinations<'_> {
+    /// Advances to the next prefix of `k - 1` values in lexicographic order, trying values from
+    /// index `from` on for the position after the current prefix.
+    ///
+    /// All values after the prefix are at least as large as the one tried, so once it times the
+    /// number of values still missing exceeds what is left of the target, so do all larger ones.
+    fn advance(&mut self, mut from: usize) -> bool {
+        while self.prefix.len() < self.k - 1 {
+            let missing = (self.k - self.prefix.len()) as i128;
+            let left = self.target - self.prefix_sum();
+            let next = (from..self.values.len())
+                .take_while(|idx| i128::from(self.values[*idx].0).saturating_mul(missing) <= left)
+                .find(|idx| self.used(*idx) < self.values[*idx].1);
+
+            match next {
+                // The next position may take the same value again
+                Some(idx) => {
+                    self.prefix.push(idx);
+                    from = idx;
+                }
+                None => match self.prefix.pop() {
+                    Some(idx) => from = idx + 1,
+                    None => return false,
+                },
+            }
+        }
+        true
+    }
+
+    /// The combination of the current prefix and the value completing it, if there is one
+    fn complete(&self) -> Option<Combination> {
+        trace::count("lookups");
+        let from = self.prefix.last().copied().unwrap_or(0);
+        let last = i64::try_from(self.target - self.prefix_sum()).ok()?;
+        let idx = from
+            + self.values[from..]
+                .binary_search_by_key(&last, |(value, _)| *value)
+                .ok()?;
+        if self.used(idx) == self.values[idx].1 {
+            return None;
+        }
+
+        let mut indices = self.prefix.clone();
+        indices.push(idx);
+        let mut ways = 1;
+        for run in indices.chunk_by(|a, b| a == b) {
+            ways = binomial(self.values[run[0]].1, run.len()).saturating_mul(ways);
+        }
+
+        Some(Combination {
+            values: indices.iter().map(|idx| self.values[*idx].0).collect(),
+            ways,
+        })
+    }
+
+    fn prefix_sum(&self) -> i128 {
+        self.prefix
+            .iter()
+            .map(|idx| i128::from(self.values[*idx].0))
+            .sum()
+    }
+
+    /// How often the value at `idx` is used by the prefix, where equal values are adjacent
+    fn used(&self, idx: usize) -> usize {
+        self.prefix
+            .iter()
+            .rev()
+            .take_while(|used| **used == idx)
+            .count()
+    }
+}
+
+impl Iterator for Combinations<'_> {
+    type Item = Combination;
+
+    fn next(&mut self) -> Option<Combination> {
+        loop {
+            let found = match self.state {
+                State::Start => self.advance(0),
+                State::Searching => match self.prefix.pop() {
+                    Some(idx) => self.advance(idx + 1),
+                    None => false,
+                },
+                State::Done => return None,
+            };
+            if !found {
+                self.state = State::Done;
+                return None;
+            }
+
+            self.state = State::Searching;
+            if let Some(combination) = self.complete() {
+                return Some(combination);
+            }
+        }
+    }
+}
+
+impl FusedIterator for Combinations<'_> {}
+
+/// Number of ways to choose `k` of `n` items, saturating at `u64::MAX`
+fn binomial(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let (n, k) = (n as u128, k.min(n - k) as u128);
+
+    // Each step yields the binomial of `i + 1`, so the division is exact. They only grow up to
+    // half of `n`, so once one exceeds 64 bits the result does, too
+    let mut ways = 1u128;
+    for i in 0..k {
+        ways = ways * (n - i) / (i + 1);
+        if ways > u128::from(u64::MAX) {
+            return u64::MAX;
+        }
+    }
+    ways as u64
+}
+
+fn find_entries(input: &Expenses, k: usize, params: &Params) -> Result<Entries> {
+    if k == 0 {
+        return Err(Error::invalid_parameter(
+            "at least one entry has to be looked for",
+        ));
+    }
+    let candidates = if params.candidates {
+        input.combinations(k, params.target).collect::<Vec<_>>()
+    } else {
+        input
+            .find_sum(k, params.target)
+            .map(|values| Combination {
+                ways: input.ways(&values),
+                values,
+            })
+            .into_iter()
+            .collect()
+    };
+    let first = candidates.first().ok_or(Error::NoSolution)?;
+
+    let product = first
+        .values
+        .iter()
+        .try_fold(1i128, |product, entry| {
+            product.checked_mul(i128::from(*entry))
+        })
+        .ok_or_else(|| Error::invalid_parameter("the product of the entries overflows 128 bits"))?;
+    Ok(Entries {
+        candidates,
+        product,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<Expenses> {
+    normalize::lines(input)
+        .map(|i| error::parse(input, i))
+        .collect()
+}
+
+fn stream_entries(reader: &mut dyn BufRead) -> impl Iterator<Item = Result<i64>> + '_ {
+    stream::lines(reader).map(|line| line?.parse(error::parse))
+}
+
+/// Generates entries above 1010, any two of which sum up to more than 2020, and plants a pair and
+/// a triple summing up to 2020 among them
+fn generate_input(rng: &mut StdRng) -> Generated {
+    retry(rng, |rng| {
+        let pair: i64 = rng.gen_range(100..1000);
+        let (a, b) = (rng.gen_range(1..400), rng.gen_range(1..400));
+
+        let mut entries = vec![pair, 2020 - pair, a, b, 2020 - a - b];
+        entries.extend((0..195).map(|_| rng.gen_range(1011..2020)));
+        entries.sort_unstable();
+        entries.dedup();
+
+        // The planted entries have to be the only solutions, even when reusing an entry
+        let n = entries.len();
+        let pairs = (0..n)
+            .flat_map(|i| (i..n).map(move |j| (i, j)))
+            .filter(|(i, j)| entries[*i] + entries[*j] == 2020)
+            .count();
+        let triples = (0..n)
+            .flat_map(|i| (i..n).flat_map(move |j| (j..n).map(move |k| (i, j, k))))
+            .filter(|(i, j, k)| entries[*i] + entries[*j] + entries[*k] == 2020)
+            .count();
+        if pairs != 1 || triples != 1 {
+            return None;
+        }
+
+        entries.shuffle(rng);
+        let input = entries.iter().map(i64::to_string).collect::<Vec<_>>();
+
+        Some(
+            Generated::new(input.join("\n"))
+                .with_answer(1, pair * (2020 - pair))
+                .with_answer(2, a * b * (2020 - a - b)),
+        )
+    })
+}
+
+#[cfg(test)]
+mod day01_test {
+    use std::collections::BTreeMap;
+
+    use aoc_core::{Answer, Error, Solution};
+    use proptest::prelude::*;
+    use serde_json::json;
+
+    use crate::{binomial, parse_input, Combination, Day01, Expenses, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_01.txt");
+
+    #[test]
+    fn test_part_1() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(input.find_sum(2, 2020).unwrap(), [211, 1809]);
+        assert_eq!(Day01::solve_part1(INPUT).unwrap().product(), 381699);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = parse_input(INPUT).unwrap();
+        assert_eq!(input.find_sum(3, 2020).unwrap(), [198, 395, 1427]);
+        assert_eq!(Day01::solve_part2(INPUT).unwrap().product(), 111605670);
+    }
+
+    #[test]
+    fn test_target() {
+        let example = "1721\n979\n366\n299\n675\n1456";
+        let params = Params {
+            target: 1345,
+            ..Params::default()
+        };
+        assert_eq!(Day01::solve_part1(example).unwrap().product(), 514579);
+        assert_eq!(
+            Day01::solve_part1_with(example, &params).unwrap().product(),
+            979 * 366
+        );
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let params = Params::default();
+
+        // Two entries of 1010 make a pair, a single one does not
+        assert_eq!(
+            Day01::solve_part1("1010\n5\n1010").unwrap().product(),
+            1010 * 1010
+        );
+        assert_eq!(
+            Day01::solve_part1("1010\n5").unwrap_err(),
+            Error::NoSolution
+        );
+        assert_eq!(
+            Day01::stream_part1(&mut "1010\n5".as_bytes(), &params).unwrap_err(),
+            Error::NoSolution
+        );
+        assert_eq!(
+            Day01::solve_part2("1000\n20\n1000").unwrap().product(),
+            1000 * 1000 * 20
+        );
+        assert_eq!(
+            Day01::solve_part2("1000\n20").unwrap_err(),
+            Error::NoSolution
+        );
+
+        let input = parse_input("3\n1\n3\n3").unwrap();
+        assert_eq!((input.count(3), input.count(1), input.count(2)), (3, 1, 0));
+    }
+
+    #[test]
+    fn test_entries() {
+        let example = "1721\n979\n366\n299\n675\n1456";
+        let input = parse_input(example).unwrap();
+        assert_eq!(input.find_sum(1, 979).unwrap(), [979]);
+        assert_eq!(
+            input.find_sum(4, 1721 + 979 + 366 + 299).unwrap(),
+            [299, 366, 979, 1721]
+        );
+        assert_eq!(input.find_sum(6, 5496).unwrap().len(), 6);
+        assert_eq!(input.find_sum(7, 5496), None);
+
+        let params = Params {
+            part1_entries: 4,
+            part2_entries: 0,
+            ..Params::default()
+        };
+        assert_eq!(
+            Day01::solve_part1_with("1\n-2\n1010\n1011", &params)
+                .unwrap()
+                .product(),
+            -2 * 1010 * 1011
+        );
+        assert!(Day01::stream_part1(&mut "1\n-2\n1010\n1011".as_bytes(), &params).is_ok());
+        assert!(Day01::solve_part2_with(example, &params).is_err());
+    }
+
+    #[test]
+    fn test_large_entries() {
+        let report = [i64::MAX, 3, i64::MIN + 1, -4, i64::MAX - 2]
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let params = Params {
+            target: -1,
+            ..Params::default()
+        };
+        assert_eq!(
+            Day01::solve_part1_with(&report, &params).unwrap().entries(),
+            [-4, 3]
+        );
+
+        let params = Params {
+            target: 0,
+            ..Params::default()
+        };
+        assert_eq!(
+            Day01::solve_part1_with(&report, &params).unwrap().product(),
+            -i128::from(i64::MAX) * i128::from(i64::MAX)
+        );
+
+        // The product of (i64::MIN + 1) * 3 * (i64::MAX - 2) does not even fit 128 bits
+        let params = Params {
+            target: 1,
+            ..Params::default()
+        };
+        assert_eq!(
+            Day01::solve_part2_with(&report, &params).unwrap_err(),
+            Error::invalid_parameter("the product of the entries overflows 128 bits")
+        );
+    }
+
+    #[test]
+    fn test_first_candidate() {
+        // Only the first combination is looked for unless all are asked for
+        let report = "1010\n1000\n1010\n1020\n1010";
+        let params = Params::default();
+        let answer = Day01::solve_part1_with(report, &params).unwrap();
+        assert_eq!(answer.product(), 1000 * 1020);
+        assert_eq!(
+            answer.candidates(),
+            [Combination {
+                values: vec![1000, 1020],
+                ways: 1
+            }]
+        );
+
+        let answer = Day01::solve_part1_with("1010\n1010\n0\n1010", &params).unwrap();
+        assert_eq!(answer.candidates()[0].ways, 3);
+        assert_eq!(
+            Day01::stream_part2(&mut INPUT.as_bytes(), &params)
+                .unwrap()
+                .product(),
+            111605670
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let params = Params::default();
+        let answer = Day01::stream_part1(&mut INPUT.as_bytes(), &params).unwrap();
+        assert_eq!(answer.product(), 381699);
+        let answer = Day01::stream_part2(&mut INPUT.as_bytes(), &params).unwrap();
+        assert_eq!(answer.product(), 111605670);
+
+        // The answer does not depend on the order the entries are read in
+        let answer = Day01::stream_part1(&mut "1019\n1010\n1001\n1010".as_bytes(), &params);
+        assert_eq!(answer.unwrap().entries(), [1001, 1019]);
+        assert_eq!(
+            Day01::stream_part1(&mut "1\n\n x".as_bytes(), &params).unwrap_err(),
+            Error::parse(3, 2, "\"x\": invalid digit found in string")
+        );
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(3, 4), 0);
+        // The products on the way exceed 64 bits long before the result does
+        assert_eq!(binomial(67, 33), 14226520737620288370);
+        assert_eq!(binomial(68, 34), u64::MAX);
+        assert_eq!(binomial(usize::MAX, 2), u64::MAX);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let combination = |values: &[i64], ways| Combination {
+            values: values.to_vec(),
+            ways,
+        };
+        let input = parse_input("1010\n1000\n1010\n1020\n1010\n20\n0\n1000").unwrap();
+
+        assert_eq!(
+            input.combinations(2, 2020).collect::<Vec<_>>(),
+            [combination(&[1000, 1020], 2), combination(&[1010, 1010], 3)]
+        );
+        assert_eq!(
+            input.combinations(3, 2020).collect::<Vec<_>>(),
+            [
+                combination(&[0, 1000, 1020], 2),
+                combination(&[0, 1010, 1010], 3),
+                combination(&[20, 1000, 1000], 1),
+            ]
+        );
+        assert_eq!(input.combinations(3, 2020).count(), 3);
+        assert_eq!(input.combinations(0, 0).next(), None);
+
+        // The answer is the first combination, the others are listed in the details if asked for
+        let params = Params {
+            candidates: true,
+            ..Params::default()
+        };
+        let answer = Day01::solve_part1_with("1010\n1000\n1010\n1020", &params).unwrap();
+        assert_eq!(answer.product(), 1000 * 1020);
+        assert_eq!(answer.candidates().len(), 2);
+        assert_eq!(
+            answer.details().unwrap(),
+            json!({
+                "entries": [1000, 1020],
+                "candidates": [
+                    { "entries": [1000, 1020], "ways": 1 },
+                    { "entries": [1010, 1010], "ways": 1 },
+                ],
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_find_sum(
+            mut entries in prop::collection::vec(-100i64..2020, 0..50),
+            planted in prop::collection::vec(-100i64..2020, 1..6),
+        ) {
+            let target = planted.iter().sum();
+            entries.extend(&planted);
+            let expenses = entries.iter().copied().collect::<Expenses>();
+
+            let found = expenses.find_sum(planted.len(), target).unwrap();
+            prop_assert_eq!(found.iter().sum::<i64>(), target);
+            // No value is used more often than it occurs
+            prop_assert!(found
+                .iter()
+                .all(|value| found.iter().filter(|x| *x == value).count() <= expenses.count(*value)));
+        }
+
+        #[test]
+        fn test_all_combinations(entries in prop::collection::vec(0i64..30, 0..12), target in 0i64..60) {
+            let expenses = entries.iter().copied().collect::<Expenses>();
+
+            // Every subset of three entries, by its sorted values
+            let mut expected = BTreeMap::new();
+            for i in 0..entries.len() {
+                for j in i + 1..entries.len() {
+                    for k in j + 1..entries.len() {
+                        let mut values = vec![entries[i], entries[j], entries[k]];
+                        if values.iter().sum::<i64>() == target {
+                            values.sort_unstable();
+                            *expected.entry(values).or_insert(0) += 1;
+                        }
+                    }
+                }
+            }
+            let expected = expected
+                .into_iter()
+                .map(|(values, ways)| Combination { values, ways })
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(expenses.combinations(3, target).collect::<Vec<_>>(), expected);
+        }
+
+        #[test]
+        fn test_no_solution(entries in prop::collection::vec(1011i64..2020, 0..50), k in 2..5usize) {
+            // Any two entries sum up to more than 2020
+            let expenses = entries.into_iter().collect::<Expenses>();
+            prop_assert_eq!(expenses.find_sum(k, 2020), None);
+        }
+
+        #[test]
+        fn test_first_combination(
+            entries in prop::collection::vec(-50i64..50, 0..14),
+            k in 1..6usize,
+            target in -100i64..100,
+        ) {
+            let expenses = entries.into_iter().collect::<Expenses>();
+            let first = expenses.combinations(k, target).next().map(|combination| combination.values);
+            prop_assert_eq!(expenses.find_sum(k, target), first);
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use std::{fmt, io::BufRead};
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, StdRng},
+    normalize, params, stream, trace, Error, Result, Solution,
+};
+use policy::{PasswordPolicy, Policy};
+
+pub mod policy;
+
+pub struct Day02;
+
+params! {
+    pub struct Params {
+        /// Policy the passwords of part 1 are checked with: sled (the letter occurs min to max
+        /// times), toboggan (the letter is at exactly one of the positions min and max),
+        /// regex(<expression>), classes(<lower, upper, digit or symbol>, ...),
+        /// forbid(<substring>, ...), or all(<policy>, ...) and any(<policy>, ...) to combine them
+        part1_policy: Policy = Policy::sled(),
+        /// Policy the passwords of part 2 are checked with, written like the one of part 1
+        part2_policy: Policy = Policy::toboggan(),
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    type Input<'a> = Vec<PasswordRule>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        trace::count_by("passwords checked", input.len() as u64);
+        Ok(input
+            .iter()
+            .filter(|rule| params.part1_policy.is_valid(rule))
+            .count())
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        trace::count_by("passwords checked", input.len() as u64);
+        Ok(input
+            .iter()
+            .filter(|rule| params.part2_policy.is_valid(rule))
+            .count())
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        count_valid(reader, &params.part1_policy)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        count_valid(reader, &params.part2_policy)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Line of the password database, a password together with the rule it was set under
+#[derive(Debug, PartialEq, Eq)]
+pub struct PasswordRule {
+    min: usize,
+    max: usize,
+    char: char,
+    password: String,
+}
+
+impl PasswordRule {
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    pub fn char(&self) -> char {
+        self.char
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    /// Character at the 1-based `position` of the password, none past its end. Positions count
+    /// characters, not bytes, so that non-ASCII passwords are checked character by character
+    pub fn char_at(&self, position: usize) -> Option<char> {
+        let idx = position.checked_sub(1)?;
+        self.password.chars().nth(idx)
+    }
+}
+
+impl fmt::Display for PasswordRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.min, self.max, self.char, self.password
+        )
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<PasswordRule>> {
+    // Whitespace around a password belongs to it
+    normalize::untrimmed_lines(input)
+        .map(|line| parse_rule(input, line))
+        .collect()
+}
+
+/// Counts the rules whose passwords comply with `policy` while reading them, one at a time
+fn count_valid(reader: &mut dyn BufRead, policy: &dyn PasswordPolicy) -> Result<usize> {
+    stream::lines(reader).try_fold(0, |valid, line| {
+        trace::count("passwords checked");
+        let rule = line?.parse(|raw, _| parse_rule(raw, normalize::strip_ending(raw)))?;
+        Ok(valid + policy.is_valid(&rule) as usize)
+    })
+}
+
+/// Parses a line of the password database, following
+///
+/// ```text
+/// rule     = minimum "-" maximum " "+ char ":" " "? password
+/// minimum  = number
+/// maximum  = number
+/// char     = any character but whitespace
+/// password = any characters up to the line ending, at least one
+/// ```
+///
+/// The character is a single Unicode scalar value, as are the positions the policies count, and
+/// as the password starts after the `:` following the character, both may be `-` or `:`. Only
+/// whitespace before the rule is skipped, the password keeps all of it
+fn parse_rule(input: &str, line: &str) -> Result<PasswordRule> {
+    let mut rest = line.trim_start();
+
+    let min = error::parse(
+        input,
+        token(input, &mut rest, "minimum", |c| {
+            c == '-' || c.is_whitespace()
+        })?,
+    )?;
+    expect(input, &mut rest, '-', "after the minimum")?;
+    let max = error::parse(
+        input,
+        token(input, &mut rest, "maximum", char::is_whitespace)?,
+    )?;
+    let spaces = rest.trim_start_matches(' ');
+    if spaces.len() == rest.len() && !rest.is_empty() {
+        return Err(Error::parse_at(
+            input,
+            rest,
+            "expected ' ' after the maximum",
+        ));
+    }
+    rest = spaces;
+
+    let char = match rest.chars().next() {
+        Some(char) if !char.is_whitespace() => char,
+        Some(_) => return Err(Error::parse_at(input, rest, "expected a character")),
+        None => return Err(Error::missing(input, line, "character")),
+    };
+    rest = &rest[char.len_utf8()..];
+    // Without a `:` there is no password either
+    if rest.is_empty() {
+        return Err(Error::missing(input, line, "password"));
+    }
+    expect(input, &mut rest, ':', "after the character")?;
+
+    let password = rest.strip_prefix(' ').unwrap_or(rest);
+    if password.is_empty() {
+        return Err(Error::missing(input, line, "password"));
+    }
+
+    Ok(PasswordRule {
+        min,
+        max,
+        char,
+        password: password.to_string(),
+    })
+}
+
+/// Takes the `what` from the start of `rest` up to the first character matching `end`
+fn token<'a>(
+    input: &str,
+    rest: &mut &'a str,
+    what: &str,
+    end: impl Fn(char) -> bool,
+) -> Result<&'a str> {
+    let len = rest.find(end).unwrap_or(rest.len());
+    let (token, after) = rest.split_at(len);
+    if token.is_empty() {
+        let message = if rest.is_empty() {
+            format!("missing {}", what)
+        } else {
+            format!("expected the {}", what)
+        };
+        return Err(Error::parse_at(input, rest, message));
+    }
+    *rest = after;
+    Ok(token)
+}
+
+/// Skips `expected` at the start of `rest`, which has to come `context`
+fn expect(input: &str, rest: &mut &str, expected: char, context: &str) -> Result<()> {
+    match rest.strip_prefix(expected) {
+        Some(after) => {
+            *rest = after;
+            Ok(())
+        }
+        None if rest.is_empty() => Err(Error::parse_at(
+            input,
+            rest,
+            format!("missing {:?} {}", expected, context),
+        )),
+        None => Err(Error::parse_at(
+            input,
+            rest,
+            format!("expected {:?} {}", expected, context),
+        )),
+    }
+}
+
+/// Generates passwords from a small alphabet so that the policies' characters appear often
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let mut lines = Vec::new();
+    let (mut valid, mut valid2) = (0, 0);
+
+    for _ in 0..1000 {
+        let min = rng.gen_range(1..=5);
+        let max = rng.gen_range(min + 1..=min + 8);
+        let char = rng.gen_range(b'a'..=b'f') as char;
+        let password = (0..rng.gen_range(max..max + 6))
+            .map(|_| rng.gen_range(b'a'..=b'f') as char)
+            .collect::<String>();
+
+        let count = password.chars().filter(|c| *c == char).count();
+        if (min..=max).contains(&count) {
+            valid += 1;
+        }
+        let bytes = password.as_bytes();
+        if (bytes[min - 1] == char as u8) != (bytes[max - 1] == char as u8) {
+            valid2 += 1;
+        }
+
+        lines.push(format!("{}-{} {}: {}", min, max, char, password));
+    }
+
+    Generated::new(lines.join("\n"))
+        .with_answer(1, valid)
+        .with_answer(2, valid2)
+}
+
+#[cfg(test)]
+mod day02_test {
+    use aoc_core::{Error, Solution};
+    use proptest::prelude::*;
+
+    use crate::{
+        parse_input,
+        policy::{PasswordPolicy, Policy},
+        Day02, Params, PasswordRule,
+    };
+
+    const INPUT: &str = include_str!("../../inputs/day_02.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day02::solve_part1(INPUT).unwrap(), 398);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day02::solve_part1("1-3 a: abcde\n1-x b: cdefg").unwrap_err(),
+            Error::parse(2, 3, "\"x\": invalid digit found in string")
+        );
+        assert_eq!(
+            Day02::solve_part1("1-3 a: abcde\n2-9 c").unwrap_err(),
+            Error::parse(2, 6, "missing password")
+        );
+
+        let error = |line| Day02::solve_part1(line).unwrap_err();
+        assert_eq!(error("-3 a: x"), Error::parse(1, 1, "expected the minimum"));
+        assert_eq!(
+            error("13 a: x"),
+            Error::parse(1, 3, "expected '-' after the minimum")
+        );
+        assert_eq!(
+            error("ü-3 a: x"),
+            Error::parse(1, 1, "\"ü\": invalid digit found in string")
+        );
+        assert_eq!(error("1-"), Error::parse(1, 3, "missing maximum"));
+        assert_eq!(
+            error("1-3\ta: x"),
+            Error::parse(1, 4, "expected ' ' after the maximum")
+        );
+        assert_eq!(error("1-3"), Error::parse(1, 4, "missing character"));
+        // Columns count characters, not bytes
+        assert_eq!(
+            error("1-3 éa: x"),
+            Error::parse(1, 6, "expected ':' after the character")
+        );
+        assert_eq!(error("1-3 a:"), Error::parse(1, 7, "missing password"));
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let input = "1-2 a: a \r\n\n  1-1 a: b\t\n1-1 a:  \n";
+        let passwords = parse_input(input)
+            .unwrap()
+            .iter()
+            .map(|rule| rule.password().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(passwords, ["a ", "b\t", " "]);
+
+        let params = Params {
+            part1_policy: "regex(\\s$)".parse().unwrap(),
+            ..Params::default()
+        };
+        assert_eq!(Day02::solve_part1_with(input, &params), Ok(3));
+        assert_eq!(Day02::stream_part1(&mut input.as_bytes(), &params), Ok(3));
+    }
+
+    #[test]
+    fn test_delimiters() {
+        let input = parse_input(
+            "1-3 a: a-b:c\n1-2 -: --:\n2-3 :: a::\n1-3 é: ééa\n1-1 a: 1-3 a: b\n1-3 a:abc\n1-3 a:  abc",
+        )
+        .unwrap();
+        let passwords = input.iter().map(PasswordRule::password).collect::<Vec<_>>();
+        assert_eq!(
+            passwords,
+            ["a-b:c", "--:", "a::", "ééa", "1-3 a: b", "abc", " abc"]
+        );
+        assert_eq!(input[1].char(), '-');
+        assert_eq!(input[2].char(), ':');
+        assert_eq!(input[3].char_at(3), Some('a'));
+
+        let sled = input
+            .iter()
+            .map(|rule| Policy::sled().is_valid(rule))
+            .collect::<Vec<_>>();
+        assert_eq!(sled, [true, true, true, true, true, true, true]);
+        let toboggan = input
+            .iter()
+            .map(|rule| Policy::toboggan().is_valid(rule))
+            .collect::<Vec<_>>();
+        assert_eq!(toboggan, [true, false, false, true, false, true, false]);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day02::solve_part2(INPUT).unwrap(), 562);
+    }
+
+    #[test]
+    fn test_policies() {
+        let example = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+        let params = Params {
+            part1_policy: "any(sled, toboggan)".parse().unwrap(),
+            part2_policy: "all(toboggan, forbid(ccc))".parse().unwrap(),
+        };
+        assert_eq!(Day02::solve_part1_with(example, &params), Ok(2));
+        assert_eq!(Day02::solve_part2_with(example, &params), Ok(1));
+        assert_eq!(Day02::stream_part2(&mut example.as_bytes(), &params), Ok(1));
+
+        let params = Params {
+            part1_policy: Policy::toboggan(),
+            part2_policy: Policy::sled(),
+        };
+        assert_eq!(Day02::solve_part1_with(INPUT, &params), Ok(562));
+        assert_eq!(Day02::solve_part2_with(INPUT, &params), Ok(398));
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            Day02::stream_part1(&mut INPUT.as_bytes(), &Params::default()),
+            Ok(398)
+        );
+        assert_eq!(
+            Day02::stream_part2(&mut INPUT.as_bytes(), &Params::default()),
+            Ok(562)
+        );
+        assert_eq!(
+            Day02::stream_part1(
+                &mut "1-3 a: abcde\r\n\r\n2-9 c".as_bytes(),
+                &Params::default()
+            ),
+            Err(Error::parse(3, 6, "missing password"))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_rule(
+            min in 0..100usize,
+            max in 0..100usize,
+            char in any::<char>().prop_filter("not whitespace", |c| !c.is_whitespace()),
+            // Only line endings cannot be told apart from the end of a password
+            password in "[^\n\r]+",
+        ) {
+            let rule = PasswordRule { min, max, char, password };
+            let parsed = parse_input(&rule.to_string()).unwrap();
+            prop_assert_eq!(parsed, vec![rule]);
+        }
+    }
+}
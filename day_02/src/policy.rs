@@ -0,0 +1,432 @@
+//! Policies a password has to comply with, given the rule it is listed with.
+//!
+//! Besides the policies of both parts, policies can require a password to match a regular
+//! expression, to contain characters of certain classes or to avoid substrings, and they can be
+//! combined. [`Policy`] selects them by name, written like `all(sled, classes(digit), forbid(abc))`.
+
+use std::{fmt, str::FromStr, sync::Arc};
+
+use regex::Regex;
+
+use crate::PasswordRule;
+
+/// Checks the password of a rule
+pub trait PasswordPolicy: fmt::Debug + Send + Sync {
+    /// Why the password of `rule` violates the policy, `None` if it complies
+    fn check(&self, rule: &PasswordRule) -> Option<String>;
+
+    fn is_valid(&self, rule: &PasswordRule) -> bool {
+        self.check(rule).is_none()
+    }
+}
+
+/// Policy of the sled rental place: the character occurs from minimum to maximum times
+#[derive(Debug, Clone, Copy)]
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let count = rule
+            .password()
+            .chars()
+            .filter(|c| *c == rule.char())
+            .count();
+        (!(rule.min()..=rule.max()).contains(&count)).then(|| {
+            format!(
+                "contains {:?} {} times instead of {} to {}",
+                rule.char(),
+                count,
+                rule.min(),
+                rule.max()
+            )
+        })
+    }
+}
+
+/// Policy of the Official Toboggan Corporate Authentication System: the character is at exactly
+/// one of the 1-based positions given by minimum and maximum
+#[derive(Debug, Clone, Copy)]
+pub struct Positions;
+
+impl PasswordPolicy for Positions {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let first = rule.char_at(rule.min()) == Some(rule.char());
+        let second = rule.char_at(rule.max()) == Some(rule.char());
+        let (at, conjunction) = match (first, second) {
+            (true, true) => ("both", "and"),
+            (false, false) => ("neither", "nor"),
+            _ => return None,
+        };
+        Some(format!(
+            "{:?} is at {} position {} {} {}",
+            rule.char(),
+            at,
+            rule.min(),
+            conjunction,
+            rule.max()
+        ))
+    }
+}
+
+/// The password matches a regular expression, which is not anchored unless it says so
+#[derive(Debug, Clone)]
+pub struct Pattern(pub Regex);
+
+impl PasswordPolicy for Pattern {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        (!self.0.is_match(rule.password())).then(|| format!("does not match /{}/", self.0))
+    }
+}
+
+/// Kinds of characters a password can be required to contain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    /// Any character which is neither alphanumeric nor whitespace
+    Symbol,
+}
+
+impl CharClass {
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CharClass::Lower => "lower",
+            CharClass::Upper => "upper",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for CharClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lower" => Ok(CharClass::Lower),
+            "upper" => Ok(CharClass::Upper),
+            "digit" => Ok(CharClass::Digit),
+            "symbol" => Ok(CharClass::Symbol),
+            _ => Err(format!(
+                "unknown character class {:?}, expected lower, upper, digit or symbol",
+                s
+            )),
+        }
+    }
+}
+
+/// The password contains at least one character of each class
+#[derive(Debug, Clone)]
+pub struct CharClasses(pub Vec<CharClass>);
+
+impl PasswordPolicy for CharClasses {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let missing = self
+            .0
+            .iter()
+            .filter(|class| !rule.password().chars().any(|c| class.contains(c)))
+            .map(CharClass::to_string)
+            .collect::<Vec<_>>();
+        (!missing.is_empty()).then(|| format!("contains no {} character", missing.join(", ")))
+    }
+}
+
+/// The password contains none of the substrings
+#[derive(Debug, Clone)]
+pub struct Forbidden(pub Vec<String>);
+
+impl PasswordPolicy for Forbidden {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let found = self
+            .0
+            .iter()
+            .filter(|forbidden| rule.password().contains(forbidden.as_str()))
+            .map(|forbidden| format!("{:?}", forbidden))
+            .collect::<Vec<_>>();
+        (!found.is_empty()).then(|| format!("contains {}", found.join(", ")))
+    }
+}
+
+/// All of the policies hold, failing with the explanations of each one violated
+#[derive(Debug, Clone)]
+pub struct All(pub Vec<Policy>);
+
+impl PasswordPolicy for All {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let violations = self
+            .0
+            .iter()
+            .filter_map(|policy| policy.check(rule))
+            .collect::<Vec<_>>();
+        (!violations.is_empty()).then(|| violations.join("; "))
+    }
+}
+
+/// Any of the policies holds, failing with the explanations of all of them
+#[derive(Debug, Clone)]
+pub struct Any(pub Vec<Policy>);
+
+impl PasswordPolicy for Any {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        let violations = self
+            .0
+            .iter()
+            .map(|policy| policy.check(rule))
+            .collect::<Option<Vec<_>>>()?;
+        Some(format!("none of {{{}}}", violations.join(" | ")))
+    }
+}
+
+/// Policy selected by name, as a parameter.
+///
+/// `sled` and `toboggan` are the policies of part 1 and 2, `regex(...)` takes a regular expression,
+/// `classes(...)` a list of `lower`, `upper`, `digit` and `symbol`, `forbid(...)` a list of
+/// substrings and `all(...)` and `any(...)` a list of policies. Lists are separated by commas.
+#[derive(Debug, Clone)]
+pub struct Policy {
+    /// What the policy was parsed from, without whitespace around names and arguments
+    spec: String,
+    policy: Arc<dyn PasswordPolicy>,
+}
+
+impl Policy {
+    pub fn sled() -> Self {
+        Policy::new("sled", CountRange)
+    }
+
+    pub fn toboggan() -> Self {
+        Policy::new("toboggan", Positions)
+    }
+
+    fn new(spec: impl Into<String>, policy: impl PasswordPolicy + 'static) -> Self {
+        Policy {
+            spec: spec.into(),
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl PasswordPolicy for Policy {
+    fn check(&self, rule: &PasswordRule) -> Option<String> {
+        self.policy.check(rule)
+    }
+}
+
+impl PartialEq for Policy {
+    fn eq(&self, other: &Self) -> bool {
+        self.spec == other.spec
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, args) = match s.split_once('(') {
+            Some((name, rest)) => {
+                let args = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| format!("missing ) at the end of {:?}", s))?;
+                (name.trim(), Some(args))
+            }
+            None => (s, None),
+        };
+
+        match (name, args) {
+            ("sled", None) => Ok(Policy::sled()),
+            ("toboggan", None) => Ok(Policy::toboggan()),
+            // The expression is taken as it is, commas and parentheses included
+            ("regex", Some(regex)) => {
+                let regex = Regex::new(regex.trim()).map_err(|err| err.to_string())?;
+                Ok(Policy::new(format!("regex({})", regex), Pattern(regex)))
+            }
+            ("classes", Some(args)) => {
+                let classes = split(args)?
+                    .into_iter()
+                    .map(str::parse)
+                    .collect::<Result<Vec<CharClass>, _>>()?;
+                let spec = list(&classes);
+                Ok(Policy::new(
+                    format!("classes({})", spec),
+                    CharClasses(classes),
+                ))
+            }
+            ("forbid", Some(args)) => {
+                let forbidden = split(args)?
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+                let spec = forbidden.join(", ");
+                Ok(Policy::new(
+                    format!("forbid({})", spec),
+                    Forbidden(forbidden),
+                ))
+            }
+            ("all", Some(args)) | ("any", Some(args)) => {
+                let policies = split(args)?
+                    .into_iter()
+                    .map(str::parse)
+                    .collect::<Result<Vec<Policy>, _>>()?;
+                let spec = format!("{}({})", name, list(&policies));
+                match name {
+                    "all" => Ok(Policy::new(spec, All(policies))),
+                    _ => Ok(Policy::new(spec, Any(policies))),
+                }
+            }
+            ("sled", Some(_)) | ("toboggan", Some(_)) => {
+                Err(format!("{} does not take arguments", name))
+            }
+            ("regex", None)
+            | ("classes", None)
+            | ("forbid", None)
+            | ("all", None)
+            | ("any", None) => Err(format!("{} needs arguments in parentheses", name)),
+            _ => Err(format!(
+                "unknown policy {:?}, expected sled, toboggan, regex, classes, forbid, all or any",
+                name
+            )),
+        }
+    }
+}
+
+/// Splits `args` at the commas outside of parentheses, none of which may be empty
+fn split(args: &str) -> Result<Vec<&str>, String> {
+    let (mut parts, mut depth, mut start) = (Vec::new(), 0usize, 0);
+    for (idx, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("unbalanced ) in {:?}", args))?
+            }
+            ',' if depth == 0 => {
+                parts.push(args[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        return Err(format!("unbalanced ( in {:?}", args));
+    }
+    parts.push(args[start..].trim());
+
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(format!("empty argument in {:?}", args));
+    }
+    Ok(parts)
+}
+
+fn list<T: fmt::Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod policy_test {
+    use crate::{
+        parse_rule,
+        policy::{PasswordPolicy, Policy},
+    };
+
+    fn check(policy: &str, line: &str) -> Option<String> {
+        let policy = policy.parse::<Policy>().unwrap();
+        policy.check(&parse_rule(line, line).unwrap())
+    }
+
+    #[test]
+    fn test_puzzle_policies() {
+        assert_eq!(check("sled", "1-3 a: abcde"), None);
+        assert_eq!(
+            check("sled", "1-3 b: cdefg"),
+            Some("contains 'b' 0 times instead of 1 to 3".to_string())
+        );
+        assert_eq!(check("toboggan", "1-3 a: abcde"), None);
+        assert_eq!(
+            check("toboggan", "2-9 c: ccccccccc"),
+            Some("'c' is at both position 2 and 9".to_string())
+        );
+        // Positions past the end of the password do not hold the character
+        assert_eq!(check("toboggan", "2-30 c: cc"), None);
+        assert_eq!(
+            check("toboggan", "0-30 c: cc"),
+            Some("'c' is at neither position 0 nor 30".to_string())
+        );
+    }
+
+    #[test]
+    fn test_composed_policies() {
+        assert_eq!(check("regex(^[a-e]+$)", "1-3 a: abcde"), None);
+        assert_eq!(
+            check("regex(^(ab|cd){2,}$)", "1-3 a: abcde"),
+            Some("does not match /^(ab|cd){2,}$/".to_string())
+        );
+        assert_eq!(
+            check("classes(lower, digit, symbol)", "1-3 a: abc1"),
+            Some("contains no symbol character".to_string())
+        );
+        assert_eq!(check("classes(upper)", "1-3 a: Ärger"), None);
+        assert_eq!(
+            check("forbid(ab, de, xy)", "1-3 a: abcde"),
+            Some("contains \"ab\", \"de\"".to_string())
+        );
+        assert_eq!(
+            check("all(sled, forbid(cd), classes(digit))", "1-3 a: aaaacd1"),
+            Some("contains 'a' 4 times instead of 1 to 3; contains \"cd\"".to_string())
+        );
+        assert_eq!(check("any(sled, toboggan)", "1-3 a: aabaa"), None);
+        assert_eq!(
+            check("any(sled, forbid(a))", "1-3 a: aaaa"),
+            Some("none of {contains 'a' 4 times instead of 1 to 3 | contains \"a\"}".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        let policy = " all( sled,any(toboggan , regex(a(b|c)) ), forbid(x,y)) "
+            .parse::<Policy>()
+            .unwrap();
+        assert_eq!(
+            policy.to_string(),
+            "all(sled, any(toboggan, regex(a(b|c))), forbid(x, y))"
+        );
+        assert_eq!(policy.to_string().parse::<Policy>(), Ok(policy));
+
+        let error = |spec: &str| spec.parse::<Policy>().unwrap_err();
+        assert_eq!(
+            error("strict"),
+            "unknown policy \"strict\", expected sled, toboggan, regex, classes, forbid, all or any"
+        );
+        assert_eq!(error("all"), "all needs arguments in parentheses");
+        assert_eq!(error("sled(1)"), "sled does not take arguments");
+        assert_eq!(error("any(sled"), "missing ) at the end of \"any(sled\"");
+        assert_eq!(error("all(sled,)"), "empty argument in \"sled,\"");
+        assert_eq!(error("all(sled))"), "unbalanced ) in \"sled)\"");
+        assert!(error("classes(lower, vowel)").starts_with("unknown character class \"vowel\""));
+        assert!(error("regex(a(b)").contains("unclosed group"));
+    }
+}
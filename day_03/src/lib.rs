@@ -0,0 +1,185 @@
+use std::{fmt, str::FromStr};
+
+use aoc_core::{
+    generate::{Generated, Rng, StdRng},
+    params,
+    params::List,
+    trace, Error, Result, Solution,
+};
+use aoc_grid::{Grid, Point};
+
+pub struct Day03;
+
+params! {
+    pub struct Params {
+        /// Slope of part 1, as columns right and rows down per step
+        slope: Slope = Slope::new(3, 1),
+        /// Slopes of part 2, whose numbers of trees are multiplied
+        slopes: List<Slope> = List(vec![
+            Slope::new(1, 1),
+            Slope::new(3, 1),
+            Slope::new(5, 1),
+            Slope::new(7, 1),
+            Slope::new(1, 2),
+        ]),
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    type Input<'a> = Grid<bool>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        count_trees(input, params.slope.right, params.slope.down)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        params
+            .slopes
+            .iter()
+            .map(|slope| count_trees(input, slope.right, slope.down))
+            .product()
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Direction of the toboggan, written as `right/down`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: usize, down: usize) -> Self {
+        Slope { right, down }
+    }
+}
+
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (right, down) = s
+            .split_once('/')
+            .ok_or_else(|| format!("expected a slope like 3/1, found {:?}", s))?;
+        let number = |n: &str| n.trim().parse::<usize>().map_err(|err| err.to_string());
+        Ok(Slope::new(number(right)?, number(down)?))
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.right, self.down)
+    }
+}
+
+/// Counts the trees hit when moving `x` columns right and `y` rows down at a time, the map
+/// repeating to the right
+pub fn count_trees(map: &Grid<bool>, x: usize, y: usize) -> Result<usize> {
+    if y == 0 {
+        return Err(Error::invalid_parameter("the slope has to move down"));
+    }
+
+    let trees = (0..map.height())
+        .step_by(y)
+        .enumerate()
+        .filter(|(step, row)| {
+            trace::count("squares visited");
+            let column = (step * x) % map.width();
+            map[Point::new(column as isize, *row as isize)]
+        })
+        .count();
+
+    Ok(trees)
+}
+
+/// Parses the map, where `#` marks a tree
+fn parse_map(input: &str) -> Result<Grid<bool>> {
+    Grid::parse(input, |ch| match ch {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+}
+
+/// Generates a map of the size of the puzzle's, where about every fifth square is a tree
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let (width, height) = (31, 323);
+    let rows = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| if rng.gen_bool(0.2) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let trees = |right: usize, down: usize| {
+        (0..height)
+            .step_by(down)
+            .enumerate()
+            .filter(|(step, row)| rows[*row].as_bytes()[step * right % width] == b'#')
+            .count()
+    };
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+    Generated::new(rows.join("\n"))
+        .with_answer(1, trees(3, 1))
+        .with_answer(
+            2,
+            slopes.iter().map(|(x, y)| trees(*x, *y)).product::<usize>(),
+        )
+}
+
+#[cfg(test)]
+mod day03_test {
+    use aoc_core::Solution;
+
+    use crate::{Day03, Params, Slope};
+
+    const INPUT: &str = include_str!("../../inputs/day_03.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day03::solve_part1(INPUT).unwrap(), 254);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day03::solve_part2(INPUT).unwrap(), 1666768320);
+    }
+
+    #[test]
+    fn test_slopes() {
+        let params = Params {
+            slopes: "3/1, 1/2".parse().unwrap(),
+            ..Params::default()
+        };
+        assert_eq!(params.slopes[1], Slope::new(1, 2));
+        assert_eq!(params.slopes.to_string(), "3/1,1/2");
+        assert_eq!(
+            Day03::solve_part2_with(INPUT, &params).unwrap(),
+            254 * Day03::solve_part1_with(
+                INPUT,
+                &Params {
+                    slope: Slope::new(1, 2),
+                    ..Params::default()
+                }
+            )
+            .unwrap()
+        );
+        assert!("3".parse::<Slope>().is_err());
+    }
+}
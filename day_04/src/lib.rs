@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    type Input<'a> = Vec<HashMap<&'a str, &'a str>>;
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let valid_passports = input
+            .iter()
+            .filter(|s| REQUIRED_FIELDS.iter().all(|field| s.contains_key(field)))
+            .count();
+        Ok(valid_passports)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        Ok(input
+            .iter()
+            .filter(|s| REQUIRED_FIELDS.iter().all(|field| s.contains_key(field)))
+            .filter(|x| validate_passport(x))
+            .count())
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+const REQUIRED_FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const OPTIONAL_FIELDS: &[&str] = &["cid"];
+
+fn parse_input(input: &str) -> Result<Vec<HashMap<&str, &str>>> {
+    normalize::blocks(input)
+        .map(|raw_passport| parse_passport(input, raw_passport))
+        .collect()
+}
+
+fn parse_passport<'a>(input: &str, raw_passport: &'a str) -> Result<HashMap<&'a str, &'a str>> {
+    raw_passport
+        .split_whitespace()
+        .map(|field| {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| Error::parse_at(input, field, "expected key:value"))?;
+            if !REQUIRED_FIELDS.contains(&key) && !OPTIONAL_FIELDS.contains(&key) {
+                return Err(Error::parse_at(
+                    input,
+                    key,
+                    format!("unknown field {:?}", key),
+                ));
+            }
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn validate_passport(passport: &HashMap<&str, &str>) -> bool {
+    trace::count("passports validated");
+    passport.iter().all(|(&key, value)| match key {
+        "byr" => (1920..=2002).contains(&value.parse().unwrap_or(0)),
+        "iyr" => (2010..=2020).contains(&value.parse().unwrap_or(0)),
+        "eyr" => (2020..=2030).contains(&value.parse().unwrap_or(0)),
+        "hgt" => {
+            if let Some(height) = value.strip_suffix("cm") {
+                (150..=193).contains(&height.parse().unwrap_or(0))
+            } else if let Some(height) = value.strip_suffix("in") {
+                (59..=76).contains(&height.parse().unwrap_or(0))
+            } else {
+                false
+            }
+        }
+        "hcl" => {
+            value.len() == 7
+                && value.starts_with('#')
+                && value.chars().skip(1).all(|c| c.is_ascii_hexdigit())
+        }
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    })
+}
+
+/// Generates passports which are either valid, miss a required field or have one invalid value
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+    let mut passports = Vec::new();
+    let (mut complete, mut valid) = (0, 0);
+
+    for _ in 0..250 {
+        let mut fields = vec![
+            ("byr", rng.gen_range(1920..=2002).to_string()),
+            ("iyr", rng.gen_range(2010..=2020).to_string()),
+            ("eyr", rng.gen_range(2020..=2030).to_string()),
+            (
+                "hgt",
+                if rng.gen() {
+                    format!("{}cm", rng.gen_range(150..=193))
+                } else {
+                    format!("{}in", rng.gen_range(59..=76))
+                },
+            ),
+            ("hcl", format!("#{:06x}", rng.gen_range(0..0x1000000))),
+            ("ecl", EYE_COLORS.choose(rng).unwrap().to_string()),
+            ("pid", format!("{:09}", rng.gen_range(0..1_000_000_000))),
+        ];
+        if rng.gen() {
+            fields.push(("cid", rng.gen_range(100..350).to_string()));
+        }
+
+        let idx = rng.gen_range(0..REQUIRED_FIELDS.len());
+        match rng.gen_range(0..3) {
+            0 => valid += 1,
+            1 => {
+                fields[idx].1 = match fields[idx].0 {
+                    "byr" => rng.gen_range(1900..1920).to_string(),
+                    "iyr" => rng.gen_range(2000..2010).to_string(),
+                    "eyr" => rng.gen_range(2031..2040).to_string(),
+                    "hgt" => format!("{}cm", rng.gen_range(194..210)),
+                    "hcl" => format!("{:06x}", rng.gen_range(0..0x1000000)),
+                    "ecl" => "xyz".to_string(),
+                    _ => format!("{:08}", rng.gen_range(0..100_000_000)),
+                };
+                complete += 1;
+            }
+            _ => {
+                fields.remove(idx);
+            }
+        }
+
+        fields.shuffle(rng);
+        let passport = fields
+            .iter()
+            .map(|(key, value)| format!("{}:{}", key, value))
+            .fold(String::new(), |passport, field| match passport.len() {
+                0 => field,
+                _ if rng.gen_bool(0.3) => passport + "\n" + &field,
+                _ => passport + " " + &field,
+            });
+        passports.push(passport);
+    }
+
+    Generated::new(passports.join("\n\n"))
+        .with_answer(1, complete + valid)
+        .with_answer(2, valid)
+}
+
+#[cfg(test)]
+mod day04_test {
+    use aoc_core::Solution;
+
+    use crate::Day04;
+
+    const INPUT: &str = include_str!("../../inputs/day_04.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day04::solve_part1(INPUT).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day04::solve_part2(INPUT).unwrap(), 116);
+    }
+
+    #[test]
+    fn test_part_2_valid_passports() {
+        let test_input = "
+            pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+            hcl:#623a2f
+
+            eyr:2029 ecl:blu cid:129 byr:1989
+            iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+            hcl:#888785
+            hgt:164cm byr:2001 iyr:2015 cid:88
+            pid:545766238 ecl:hzl
+            eyr:2022
+
+            iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+        ";
+        assert_eq!(Day04::solve_part2(test_input).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_part_2_invalid_passports() {
+        let test_input = "
+            eyr:1972 cid:100
+            hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+            iyr:2019
+            hcl:#602927 eyr:1967 hgt:170cm
+            ecl:grn pid:012533040 byr:1946
+
+            hcl:dab227 iyr:2012
+            ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+            hgt:59cm ecl:zzz
+            eyr:2038 hcl:74454a iyr:2023
+            pid:3556412378 byr:2007
+        ";
+        assert_eq!(Day04::solve_part2(test_input).unwrap(), 0);
+    }
+}
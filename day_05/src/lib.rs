@@ -0,0 +1,164 @@
+use std::{collections::HashSet, io::BufRead};
+
+use aoc_core::{
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, stream, trace, Error, Result, Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    type Input<'a> = HashSet<usize>;
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        normalize::lines(input)
+            .map(|seat| parse_seat_id(input, seat))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        input.iter().max().copied().ok_or(Error::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let min_id = input.iter().min().ok_or(Error::NoSolution)?;
+        let max_id = input.iter().max().ok_or(Error::NoSolution)?;
+
+        for id in 8..=1016 {
+            if id < *min_id || id > *max_id {
+                continue;
+            }
+            trace::count("seat ids checked");
+            if !input.contains(&id) {
+                return Ok(id);
+            }
+        }
+
+        Err(Error::NoSolution)
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output1> {
+        let mut max = None;
+        for line in stream::lines(reader) {
+            max = max.max(Some(line?.parse(parse_seat_id)?));
+        }
+        max.ok_or(Error::NoSolution)
+    }
+
+    /// There are at most 1024 seat ids, so collecting them takes little memory however long the
+    /// input is
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        let input = stream::lines(reader)
+            .map(|line| line?.parse(parse_seat_id))
+            .collect::<Result<_>>()?;
+        Self::part2(&input, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Decodes a boarding pass, which has to be a slice of `input` for error reporting
+fn parse_seat_id(input: &str, seat: &str) -> Result<usize> {
+    if seat.len() != 10 {
+        return Err(Error::parse_at(input, seat, "expected 10 characters"));
+    }
+
+    seat.char_indices().try_fold(0, |id, (idx, c)| match c {
+        'F' | 'L' => Ok(id << 1),
+        'B' | 'R' => Ok(id << 1 | 1),
+        _ => Err(Error::parse_at(
+            input,
+            &seat[idx..],
+            format!("unexpected character {:?}", c),
+        )),
+    })
+}
+
+/// Encodes a seat id as boarding pass, the inverse of [`parse_seat_id`] for ids below 1024
+pub fn encode_seat_id(id: usize) -> String {
+    (0..10)
+        .rev()
+        .map(|bit| match (bit >= 3, id >> bit & 1 == 1) {
+            (true, false) => 'F',
+            (true, true) => 'B',
+            (false, false) => 'L',
+            (false, true) => 'R',
+        })
+        .collect()
+}
+
+/// Generates the boarding passes of a full flight, except for a single seat
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let (first, last) = (rng.gen_range(8..100), rng.gen_range(800..1016));
+    let missing = rng.gen_range(first + 1..last);
+
+    let mut seats = (first..=last)
+        .filter(|id| *id != missing)
+        .map(encode_seat_id)
+        .collect::<Vec<_>>();
+    seats.shuffle(rng);
+
+    Generated::new(seats.join("\n"))
+        .with_answer(1, last)
+        .with_answer(2, missing)
+}
+
+#[cfg(test)]
+mod day05_test {
+    use aoc_core::{Error, Solution};
+    use proptest::prelude::*;
+
+    use crate::{encode_seat_id, parse_seat_id, Day05};
+
+    const INPUT: &str = include_str!("../../inputs/day_05.txt");
+
+    #[test]
+    fn test_parse_seat_id() {
+        let seat = "BFFFBBFRRR";
+        assert_eq!(parse_seat_id(seat, seat).unwrap(), 567);
+        assert!(parse_seat_id("BFFFBXFRRR", "BFFFBXFRRR").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_encode_round_trips(id in 0..1024usize) {
+            let seat = encode_seat_id(id);
+            prop_assert_eq!(parse_seat_id(&seat, &seat), Ok(id));
+        }
+
+        #[test]
+        fn test_decode_round_trips(seat in "[FB]{7}[LR]{3}") {
+            let id = parse_seat_id(&seat, &seat).unwrap();
+            prop_assert!(id < 1024);
+            prop_assert_eq!(encode_seat_id(id), seat);
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day05::solve_part1(INPUT).unwrap(), 965);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day05::solve_part2(INPUT).unwrap(), 524);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(Day05::stream_part1(&mut INPUT.as_bytes(), &()), Ok(965));
+        assert_eq!(Day05::stream_part2(&mut INPUT.as_bytes(), &()), Ok(524));
+        assert_eq!(
+            Day05::stream_part1(&mut "BFFFBBFRRR\nBFFFBXFRRR".as_bytes(), &()),
+            Err(Error::parse(2, 6, "unexpected character 'X'"))
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    generate::{Generated, Rng, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        for line in normalize::lines(input) {
+            if let Some(idx) = line.find(|c: char| !c.is_ascii_lowercase()) {
+                return Err(Error::parse_at(
+                    input,
+                    &line[idx..],
+                    "expected a question from 'a' to 'z'",
+                ));
+            }
+        }
+
+        Ok(normalize::blocks(input).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        Ok(input
+            .iter()
+            .map(|group| {
+                trace::count("groups");
+                normalize::lines(group)
+                    .flat_map(|line| line.chars())
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .sum())
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        Ok(input
+            .iter()
+            .map(|group| {
+                trace::count("groups");
+                let group_size = normalize::lines(group).count();
+                normalize::lines(group)
+                    .flat_map(|line| line.chars())
+                    .fold(HashMap::<char, usize>::new(), |mut counts, c| {
+                        match counts.get_mut(&c) {
+                            Some(count) => *count += 1,
+                            None => {
+                                counts.insert(c, 1);
+                            }
+                        }
+                        counts
+                    })
+                    .iter()
+                    .filter(|(_, count)| **count == group_size)
+                    .count()
+            })
+            .sum())
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Generates groups of up to five people, each answering a random selection of questions
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let mut groups = Vec::new();
+    let (mut anyone, mut everyone) = (0, 0);
+
+    for _ in 0..500 {
+        let people = (0..rng.gen_range(1..=5))
+            .map(|_| {
+                let answers = (b'a'..=b'z')
+                    .filter(|_| rng.gen_bool(0.4))
+                    .map(char::from)
+                    .collect::<String>();
+                if answers.is_empty() {
+                    "q".to_string()
+                } else {
+                    answers
+                }
+            })
+            .collect::<Vec<_>>();
+
+        anyone += ('a'..='z')
+            .filter(|q| people.iter().any(|person| person.contains(*q)))
+            .count();
+        everyone += ('a'..='z')
+            .filter(|q| people.iter().all(|person| person.contains(*q)))
+            .count();
+
+        groups.push(people.join("\n"));
+    }
+
+    Generated::new(groups.join("\n\n"))
+        .with_answer(1, anyone)
+        .with_answer(2, everyone)
+}
+
+#[cfg(test)]
+mod day06_test {
+    use aoc_core::Solution;
+
+    use crate::Day06;
+
+    const INPUT: &str = include_str!("../../inputs/day_06.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day06::solve_part1(INPUT).unwrap(), 7110);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day06::solve_part2(INPUT).unwrap(), 3628);
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, params,
+    repl::{self, Command, Session},
+    trace, Error, Result, Solution,
+};
+
+pub struct Day07;
+
+params! {
+    pub struct Params {
+        /// Colour of the bag to carry
+        colour: String = "shiny gold".into(),
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    type Input<'a> = Graph;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        build_graph(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        count_containers(input, &params.colour)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        count_bags(input, &params.colour)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    fn explore<'a>(input: &'a Self::Input<'_>) -> Result<Box<dyn Session + 'a>> {
+        Ok(Box::new(RuleExplorer { graph: input }))
+    }
+}
+
+pub fn build_graph(input: &str) -> Result<Graph> {
+    normalize::lines(input)
+        .map(|line| parse_rule(input, line))
+        .collect()
+}
+
+fn parse_rule(input: &str, line: &str) -> Result<(String, Vec<(u8, String)>)> {
+    let (container, containees) = line
+        .strip_suffix('.')
+        .and_then(|line| line.split_once(" bags contain "))
+        .ok_or_else(|| {
+            Error::parse_at(input, line, "expected \"<colour> bags contain <bags>.\"")
+        })?;
+
+    if containees == "no other bags" {
+        return Ok((container.to_string(), Vec::new()));
+    }
+
+    let containees = containees
+        .split(", ")
+        .map(|bag| {
+            let (amount, colour) = bag
+                .split_once(' ')
+                .and_then(|(amount, colour)| {
+                    let colour = colour
+                        .strip_suffix(" bags")
+                        .or_else(|| colour.strip_suffix(" bag"))?;
+                    Some((amount, colour))
+                })
+                .ok_or_else(|| {
+                    Error::parse_at(input, bag, "expected \"<amount> <colour> bags\"")
+                })?;
+            Ok((error::parse(input, amount)?, colour.to_string()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((container.to_string(), containees))
+}
+
+/// Maps each bag colour to the amounts and colours of bags it directly contains
+pub type Graph = HashMap<String, Vec<(u8, String)>>;
+
+/// Answers questions about the bag rules, colours are given as several words
+struct RuleExplorer<'a> {
+    graph: &'a Graph,
+}
+
+impl RuleExplorer<'_> {
+    const COMMANDS: &'static [Command] = &[
+        Command {
+            name: "colours",
+            args: "[word]",
+            help: "Lists all colours, or those containing the word",
+        },
+        Command {
+            name: "contents",
+            args: "<colour>",
+            help: "Bags a bag of the colour directly contains",
+        },
+        Command {
+            name: "containers",
+            args: "<colour>",
+            help: "Bags directly containing a bag of the colour",
+        },
+        Command {
+            name: "holders",
+            args: "<colour>",
+            help: "Number of colours eventually containing a bag of the colour, as in part 1",
+        },
+        Command {
+            name: "total",
+            args: "<colour>",
+            help: "Number of bags inside a bag of the colour, as in part 2",
+        },
+    ];
+
+    fn colour(&self, args: &[&str]) -> Result<String> {
+        let colour = args.join(" ");
+        if colour.is_empty() {
+            Err(Error::invalid_parameter("missing colour"))
+        } else if !self.graph.contains_key(&colour) {
+            Err(unknown_colour(&colour))
+        } else {
+            Ok(colour)
+        }
+    }
+}
+
+impl Session for RuleExplorer<'_> {
+    fn commands(&self) -> &'static [Command] {
+        Self::COMMANDS
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        let bags = |bags: Vec<(u8, &String)>| {
+            let mut lines = bags
+                .iter()
+                .map(|(amount, colour)| format!("{} {}", amount, colour))
+                .collect::<Vec<_>>();
+            lines.sort();
+            if lines.is_empty() {
+                "none".to_string()
+            } else {
+                lines.join("\n")
+            }
+        };
+
+        match command {
+            "colours" => {
+                let word = args.first().copied().unwrap_or("");
+                let mut colours = self
+                    .graph
+                    .keys()
+                    .filter(|colour| colour.contains(word))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                colours.sort();
+                Ok(colours.join("\n"))
+            }
+            "contents" => {
+                let colour = self.colour(args)?;
+                Ok(bags(
+                    self.graph[&colour].iter().map(|(a, c)| (*a, c)).collect(),
+                ))
+            }
+            "containers" => {
+                let colour = self.colour(args)?;
+                Ok(bags(
+                    self.graph
+                        .iter()
+                        .flat_map(|(outer, contents)| {
+                            contents
+                                .iter()
+                                .filter(|(_, inner)| *inner == colour)
+                                .map(move |(amount, _)| (*amount, outer))
+                        })
+                        .collect(),
+                ))
+            }
+            "holders" => count_containers(self.graph, &self.colour(args)?).map(|n| n.to_string()),
+            "total" => count_bags(self.graph, &self.colour(args)?).map(|n| n.to_string()),
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+fn count_containers(graph: &Graph, colour: &str) -> Result<usize> {
+    if !graph.contains_key(colour) {
+        return Err(unknown_colour(colour));
+    }
+    let mut queue = VecDeque::new();
+    // The colour itself is seen from the start, so that it does not count as its own holder
+    let mut seen = HashSet::new();
+    seen.insert(colour.to_string());
+
+    queue.push_front(colour.to_string());
+
+    while let Some(next) = queue.pop_back() {
+        trace::count("bags searched");
+        let containers: Vec<String> = graph
+            .iter()
+            .filter(|(_, v)| v.iter().any(|(_, c)| c.eq(&next)))
+            .map(|(a, _)| a.clone())
+            .collect::<Vec<_>>();
+
+        // Colours seen before are not searched again, so that cycles end
+        for container in containers {
+            if seen.insert(container.clone()) {
+                queue.push_front(container);
+            }
+        }
+    }
+
+    Ok(seen.len() - 1)
+}
+
+fn count_bags(graph: &Graph, colour: &str) -> Result<usize> {
+    count_inside(graph, colour, &mut Vec::new())
+}
+
+/// Number of bags inside a bag of `colour`, which is inside the bags of `path`. A bag containing
+/// itself, even eventually, would hold infinitely many bags, as would ones too many to count
+fn count_inside<'a>(graph: &'a Graph, colour: &'a str, path: &mut Vec<&'a str>) -> Result<usize> {
+    trace::count("bags counted");
+    let contents = graph.get(colour).ok_or_else(|| unknown_colour(colour))?;
+    if path.contains(&colour) {
+        return Err(Error::NoSolution);
+    }
+
+    path.push(colour);
+    let mut total = 0usize;
+    for (amount, inner) in contents {
+        let inside = count_inside(graph, inner, path)?;
+        total = inside
+            .checked_add(1)
+            .and_then(|bags| bags.checked_mul(*amount as usize))
+            .and_then(|bags| bags.checked_add(total))
+            .ok_or(Error::NoSolution)?;
+    }
+    path.pop();
+
+    Ok(total)
+}
+
+fn unknown_colour(colour: &str) -> Error {
+    Error::invalid_parameter(format!("unknown bag colour {:?}", colour))
+}
+
+/// Generates a layered graph of bags, where bags only contain bags of deeper layers so that there
+/// are no cycles and the number of nested bags stays bounded
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const ADJECTIVES: [&str; 12] = [
+        "light", "dark", "bright", "muted", "faded", "dotted", "vibrant", "pale", "dull", "wavy",
+        "plaid", "posh",
+    ];
+    const COLOURS: [&str; 12] = [
+        "red", "orange", "yellow", "olive", "teal", "blue", "violet", "black", "white", "tan",
+        "plum", "coral",
+    ];
+    const LAYERS: usize = 6;
+
+    let mut colours = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLOURS
+                .iter()
+                .map(move |colour| format!("{} {}", adjective, colour))
+        })
+        .collect::<Vec<_>>();
+    colours.shuffle(rng);
+    colours.truncate(120);
+    colours.push("shiny gold".to_string());
+    colours.shuffle(rng);
+
+    let layer_of = colours
+        .iter()
+        .map(|colour| (colour.as_str(), rng.gen_range(0..LAYERS)))
+        .collect::<HashMap<_, _>>();
+
+    let mut graph = Graph::new();
+    for colour in &colours {
+        let layer = layer_of[colour.as_str()];
+        let deeper = colours
+            .iter()
+            .filter(|other| layer_of[other.as_str()] > layer)
+            .collect::<Vec<_>>();
+        let amount = rng.gen_range(0..=4);
+        let contents = deeper
+            .choose_multiple(rng, amount)
+            .map(|other| (rng.gen_range(1..=5), other.to_string()))
+            .collect();
+        graph.insert(colour.clone(), contents);
+    }
+
+    fn contains_gold(graph: &Graph, colour: &str) -> bool {
+        graph[colour]
+            .iter()
+            .any(|(_, inner)| inner == "shiny gold" || contains_gold(graph, inner))
+    }
+    fn nested_bags(graph: &Graph, colour: &str) -> usize {
+        graph[colour]
+            .iter()
+            .map(|(amount, inner)| *amount as usize * (1 + nested_bags(graph, inner)))
+            .sum()
+    }
+
+    let rules = colours
+        .iter()
+        .map(|colour| {
+            let contents = graph[colour]
+                .iter()
+                .map(|(amount, inner)| {
+                    let plural = if *amount == 1 { "" } else { "s" };
+                    format!("{} {} bag{}", amount, inner, plural)
+                })
+                .collect::<Vec<_>>();
+            if contents.is_empty() {
+                format!("{} bags contain no other bags.", colour)
+            } else {
+                format!("{} bags contain {}.", colour, contents.join(", "))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Generated::new(rules.join("\n"))
+        .with_answer(
+            1,
+            colours
+                .iter()
+                .filter(|colour| contains_gold(&graph, colour))
+                .count(),
+        )
+        .with_answer(2, nested_bags(&graph, "shiny gold"))
+}
+
+#[cfg(test)]
+mod day07_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{Day07, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_07.txt");
+
+    #[test]
+    fn test_part_1() {
+        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+            bright white bags contain 1 shiny gold bag.
+            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+            faded blue bags contain no other bags.
+            dotted black bags contain no other bags.";
+        assert_eq!(Day07::solve_part1(input).unwrap(), 4);
+
+        assert_eq!(Day07::solve_part1(INPUT).unwrap(), 259);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = "shiny gold bags contain 2 dark red bags.
+        dark red bags contain 2 dark orange bags.
+        dark orange bags contain 2 dark yellow bags.
+        dark yellow bags contain 2 dark green bags.
+        dark green bags contain 2 dark blue bags.
+        dark blue bags contain 2 dark violet bags.
+        dark violet bags contain no other bags.";
+
+        assert_eq!(Day07::solve_part2(input).unwrap(), 126);
+
+        assert_eq!(Day07::solve_part2(INPUT).unwrap(), 45018);
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day07::parse(INPUT).unwrap();
+        let mut session = Day07::explore(&input).unwrap();
+        let mut execute = |line: &str| {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            session.execute(words[0], &words[1..])
+        };
+
+        assert_eq!(execute("holders shiny gold").unwrap(), "259");
+        assert_eq!(execute("total shiny gold").unwrap(), "45018");
+        assert_eq!(
+            execute("contents shiny gold").unwrap(),
+            "2 clear chartreuse"
+        );
+        assert!(execute("total shiny").is_err());
+        assert!(execute("flip").is_err());
+    }
+
+    #[test]
+    fn test_colour() {
+        let input = "shiny gold bags contain 2 dark red bags.
+        dark red bags contain 2 dark orange bags.
+        dark orange bags contain no other bags.";
+        let params = Params {
+            colour: "dark red".into(),
+        };
+
+        assert_eq!(Day07::solve_part1_with(input, &params).unwrap(), 1);
+        assert_eq!(Day07::solve_part2_with(input, &params).unwrap(), 2);
+
+        // Both parts reject colours no rule mentions
+        let params = Params {
+            colour: "plaid teal".into(),
+        };
+        let unknown = Error::invalid_parameter("unknown bag colour \"plaid teal\"");
+        assert_eq!(
+            Day07::solve_part1_with(input, &params),
+            Err(unknown.clone())
+        );
+        assert_eq!(Day07::solve_part2_with(input, &params), Err(unknown));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let input = "shiny gold bags contain 1 dark red bag.
+        dark red bags contain 1 shiny gold bag.";
+
+        assert_eq!(Day07::solve_part1(input).unwrap(), 1);
+        assert_eq!(Day07::solve_part2(input), Err(Error::NoSolution));
+    }
+}
@@ -0,0 +1,557 @@
+use std::{collections::HashSet, fmt, io::BufRead};
+
+use aoc_core::{
+    error,
+    generate::{retry, Generated, Rng, SliceRandom, StdRng},
+    normalize,
+    repl::{self, Command, Session},
+    stream, trace, Answer, Error, Result, Solution,
+};
+use serde_json::{json, Value};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    type Input<'a> = Memory<'a>;
+    type Params = ();
+    type Output1 = isize;
+    type Output2 = Repair;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        find_loop(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        repair_program(input).map(|(line, accumulator)| Repair { line, accumulator })
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        Self::part1(&stream_program(reader)?, params)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        Self::part2(&stream_program(reader)?, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    fn explore<'a>(input: &'a Self::Input<'_>) -> Result<Box<dyn Session + 'a>> {
+        Ok(Box::new(Debugger::new(input.clone())))
+    }
+}
+
+/// Result of repairing the program, the answer is the final accumulator
+#[derive(Debug, PartialEq, Eq)]
+pub struct Repair {
+    /// Index of the instruction swapped between `jmp` and `nop`
+    pub line: usize,
+    pub accumulator: isize,
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.accumulator)
+    }
+}
+
+impl Answer for Repair {
+    fn details(&self) -> Option<Value> {
+        Some(json!({ "mutated_line": self.line, "accumulator": self.accumulator }))
+    }
+}
+
+/// Runs the program until an instruction is about to be executed a second time and returns the
+/// accumulator at that point
+fn find_loop(memory: &[(&str, isize)]) -> Result<isize> {
+    let mut com = Computer::new();
+    let mut seen_ops = HashSet::new();
+
+    loop {
+        // The program terminates without running into a loop
+        let next_op = *memory.get(com.ip).ok_or(Error::NoSolution)?;
+
+        if !seen_ops.insert(com.ip) {
+            break;
+        }
+
+        trace::count("instructions executed");
+        com.evaluate_line(next_op);
+    }
+
+    Ok(com.acc)
+}
+
+/// Finds the single `jmp`/`nop` swap that lets the program terminate and returns the mutated line
+/// together with the final accumulator
+pub fn repair_program(memory: &[(&str, isize)]) -> Result<(usize, isize)> {
+    let mutations = build_mutations(memory);
+
+    for (program, mutated_line) in mutations {
+        trace::count("programs tried");
+        let mut com = Computer::new();
+        let mut seen_ops = HashSet::new();
+        let mut broken = false;
+
+        loop {
+            if com.ip >= program.len() {
+                break;
+            }
+
+            let next_op = program[com.ip];
+            if !seen_ops.insert(com.ip) {
+                broken = true;
+                break;
+            }
+            trace::count("instructions executed");
+            com.evaluate_line(next_op);
+        }
+
+        if !broken {
+            return Ok((mutated_line, com.acc));
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+fn build_mutations<'a>(memory: &'a [(&'a str, isize)]) -> Vec<(Vec<(&'a str, isize)>, usize)> {
+    let mut mutated_indices = HashSet::new();
+    let mut mutations = Vec::new();
+
+    for (idx, line) in memory.iter().enumerate() {
+        if line.0.contains("jmp") {
+            let mut mutation = memory.to_owned();
+            mutation[idx] = line.to_owned();
+            mutation[idx].0 = "nop";
+
+            mutations.push((mutation, idx));
+            mutated_indices.insert(idx);
+        }
+
+        if line.0.contains("nop") {
+            let mut mutation = memory.to_owned();
+            mutation[idx] = line.to_owned();
+            mutation[idx].0 = "jmp";
+
+            mutations.push((mutation, idx));
+            mutated_indices.insert(idx);
+        }
+    }
+
+    mutations
+}
+
+struct Computer {
+    pub acc: isize,
+    pub ip: usize,
+}
+
+impl Computer {
+    fn new() -> Self {
+        Computer { acc: 0, ip: 0 }
+    }
+
+    pub fn evaluate_line(&mut self, line: (&str, isize)) {
+        match line {
+            ("nop", _) => {}
+            ("acc", v) => self.acc += v,
+            ("jmp", v) => {
+                self.ip = ((self.ip as isize) + v) as usize;
+            }
+            // The parser only accepts the three operations above
+            _ => unreachable!(),
+        }
+
+        match line {
+            ("jmp", _) => {}
+            (_, _) => self.ip += 1,
+        }
+    }
+}
+
+pub type Memory<'a> = Vec<(&'a str, isize)>;
+
+/// Runs the program instruction by instruction, remembering which ones were executed
+struct Debugger<'a> {
+    program: Memory<'a>,
+    com: Computer,
+    executed: HashSet<usize>,
+    steps: usize,
+}
+
+impl<'a> Debugger<'a> {
+    const COMMANDS: &'static [Command] = &[
+        Command {
+            name: "state",
+            args: "",
+            help: "Shows the instruction pointer and the accumulator",
+        },
+        Command {
+            name: "step",
+            args: "[n]",
+            help:
+                "Executes the next n instructions, stopping at the end or where the program loops",
+        },
+        Command {
+            name: "run",
+            args: "",
+            help: "Executes instructions until the program ends or loops, as in part 1",
+        },
+        Command {
+            name: "list",
+            args: "[line] [n]",
+            help: "Lists n instructions from the line on, > marks the next one and * executed ones",
+        },
+        Command {
+            name: "flip",
+            args: "<line>",
+            help: "Swaps the jmp or nop of a line, as in part 2",
+        },
+        Command {
+            name: "reset",
+            args: "",
+            help: "Restarts the program, keeping flipped lines",
+        },
+    ];
+
+    fn new(program: Memory<'a>) -> Self {
+        Debugger {
+            program,
+            com: Computer::new(),
+            executed: HashSet::new(),
+            steps: 0,
+        }
+    }
+
+    fn state(&self) -> String {
+        let status = if self.com.ip >= self.program.len() {
+            ", the program has ended"
+        } else if self.executed.contains(&self.com.ip) {
+            ", the next instruction was executed before"
+        } else {
+            ""
+        };
+        format!(
+            "ip {}, acc {}, {} instruction{} executed{}",
+            self.com.ip,
+            self.com.acc,
+            self.steps,
+            if self.steps == 1 { "" } else { "s" },
+            status
+        )
+    }
+
+    /// Executes the next instruction unless the program has ended, returning a line describing it
+    fn step(&mut self) -> Option<String> {
+        let (op, arg) = *self.program.get(self.com.ip)?;
+        let line = format!("{:>4}: {} {:+}", self.com.ip, op, arg);
+
+        self.executed.insert(self.com.ip);
+        self.steps += 1;
+        self.com.evaluate_line((op, arg));
+
+        Some(format!("{:<16} acc {}", line, self.com.acc))
+    }
+
+    fn ends_or_loops(&self) -> bool {
+        self.com.ip >= self.program.len() || self.executed.contains(&self.com.ip)
+    }
+}
+
+impl Session for Debugger<'_> {
+    fn commands(&self) -> &'static [Command] {
+        Self::COMMANDS
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match command {
+            "state" => Ok(self.state()),
+            "step" => {
+                let mut lines = Vec::new();
+                for _ in 0..repl::arg_or(args, 0, "number of steps", 1)? {
+                    match self.step() {
+                        Some(line) => lines.push(line),
+                        None => break,
+                    }
+                    if self.ends_or_loops() {
+                        break;
+                    }
+                }
+                lines.push(self.state());
+                Ok(lines.join("\n"))
+            }
+            "run" => {
+                while !self.ends_or_loops() {
+                    self.step();
+                }
+                Ok(self.state())
+            }
+            "list" => {
+                let start = repl::arg_or(args, 0, "line", self.com.ip.saturating_sub(3))?;
+                let n = repl::arg_or(args, 1, "number of lines", 10)?;
+                let lines = (start..self.program.len().min(start.saturating_add(n)))
+                    .map(|line| {
+                        let (op, arg) = self.program[line];
+                        let next = if line == self.com.ip { '>' } else { ' ' };
+                        let executed = if self.executed.contains(&line) {
+                            '*'
+                        } else {
+                            ' '
+                        };
+                        format!("{}{} {:>4}: {} {:+}", next, executed, line, op, arg)
+                    })
+                    .collect::<Vec<_>>();
+                Ok(lines.join("\n"))
+            }
+            "flip" => {
+                let line = repl::arg::<usize>(args, 0, "line")?;
+                let instruction = self
+                    .program
+                    .get_mut(line)
+                    .ok_or_else(|| Error::invalid_parameter(format!("no line {}", line)))?;
+                instruction.0 = match instruction.0 {
+                    "jmp" => "nop",
+                    "nop" => "jmp",
+                    op => {
+                        return Err(Error::invalid_parameter(format!(
+                            "line {} is an {}, only jmp and nop can be flipped",
+                            line, op
+                        )))
+                    }
+                };
+                Ok(format!(
+                    "{:>4}: {} {:+}",
+                    line, instruction.0, instruction.1
+                ))
+            }
+            "reset" => {
+                self.com = Computer::new();
+                self.executed.clear();
+                self.steps = 0;
+                Ok(self.state())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Memory<'_>> {
+    normalize::lines(input)
+        .map(|line| parse_instruction(input, line))
+        .collect()
+}
+
+/// The program jumps back and forth, so all of it is kept, but not the text it was read from
+fn stream_program(reader: &mut dyn BufRead) -> Result<Memory<'static>> {
+    stream::lines(reader)
+        .map(|line| line?.parse(parse_instruction))
+        .collect()
+}
+
+const OPERATIONS: [&str; 3] = ["nop", "acc", "jmp"];
+
+fn parse_instruction(input: &str, line: &str) -> Result<(&'static str, isize)> {
+    let mut parts = line.split_whitespace();
+    let op = parts
+        .next()
+        .ok_or_else(|| Error::missing(input, line, "operation"))?;
+    let op = OPERATIONS
+        .iter()
+        .find(|known| **known == op)
+        .ok_or_else(|| Error::parse_at(input, op, format!("unknown operation {:?}", op)))?;
+    let arg = parts
+        .next()
+        .ok_or_else(|| Error::missing(input, line, "argument"))?;
+    Ok((op, error::parse(input, arg)?))
+}
+
+/// Generates a program which loops, unless a single `jmp` is replaced by a `nop`.
+///
+/// The instructions executed up to that `jmp` only ever jump backwards or into traps leading back
+/// there, so changing any other instruction still loops. The instructions after it lead to the
+/// end of the program.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let len = 600;
+
+    retry(rng, |rng| {
+        let corrupted = rng.gen_range(len / 2..len * 3 / 4);
+        let mut program = vec![None; len];
+        let mut executed = Vec::new();
+
+        let mut ip = 0;
+        while ip < corrupted {
+            executed.push(ip);
+            let (op, arg) = match rng.gen_range(0..10) {
+                0..=5 => ("acc", rng.gen_range(-50..=50)),
+                6 | 7 => ("nop", rng.gen_range(0..corrupted) as isize - ip as isize),
+                _ => ("jmp", rng.gen_range(1..=(corrupted - ip).min(5)) as isize),
+            };
+            program[ip] = Some((op, arg));
+            ip = if op == "jmp" {
+                ip + arg as usize
+            } else {
+                ip + 1
+            };
+        }
+
+        let target = *executed.choose(rng)?;
+        program[corrupted] = Some(("jmp", target as isize - corrupted as isize));
+
+        ip = corrupted + 1;
+        while ip < len {
+            let (op, arg) = match rng.gen_range(0..10) {
+                0..=6 => ("acc", rng.gen_range(-50..=50)),
+                7 => ("nop", rng.gen_range(-50..=50)),
+                _ => ("jmp", rng.gen_range(1..=(len - ip).min(5)) as isize),
+            };
+            program[ip] = Some((op, arg));
+            ip = if op == "jmp" {
+                ip + arg as usize
+            } else {
+                ip + 1
+            };
+        }
+
+        // Instructions which are never reached jump back to the start of the loop
+        let program = program
+            .iter()
+            .enumerate()
+            .map(|(idx, instruction)| match instruction {
+                Some(instruction) => *instruction,
+                None => ("jmp", target as isize - idx as isize),
+            })
+            .collect::<Vec<_>>();
+
+        // Returns the accumulator and whether the program terminated
+        let run = |program: &[(&str, isize)]| {
+            let mut com = Computer::new();
+            let mut seen = HashSet::new();
+            while com.ip < program.len() && seen.insert(com.ip) {
+                com.evaluate_line(program[com.ip]);
+            }
+            (com.acc, com.ip >= program.len())
+        };
+
+        let (looping_acc, terminated) = run(&program);
+        let mut repairs = (0..len).filter_map(|idx| {
+            let mut repaired = program.clone();
+            repaired[idx].0 = match repaired[idx].0 {
+                "jmp" => "nop",
+                "nop" => "jmp",
+                _ => return None,
+            };
+            match run(&repaired) {
+                (acc, true) => Some((idx, acc)),
+                _ => None,
+            }
+        });
+        let repair = repairs.next()?;
+        if terminated || repair.0 != corrupted || repairs.next().is_some() {
+            return None;
+        }
+
+        let lines = program
+            .iter()
+            .map(|(op, arg)| format!("{} {:+}", op, arg))
+            .collect::<Vec<_>>();
+
+        Some(
+            Generated::new(lines.join("\n"))
+                .with_answer(1, looping_acc)
+                .with_answer(2, repair.1),
+        )
+    })
+}
+
+#[cfg(test)]
+mod day08_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{parse_input, repair_program, Day08};
+
+    const EXAMPLE: &str = "nop +0
+        acc +1
+        jmp +4
+        acc +3
+        jmp -3
+        acc -99
+        acc +1
+        jmp -4
+        acc +6";
+
+    const INPUT: &str = include_str!("../../inputs/day_08.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day08::solve_part1(INPUT).unwrap(), 1384);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let memory = parse_input(INPUT).unwrap();
+        assert_eq!(repair_program(&memory).unwrap(), (193, 761));
+        assert_eq!(Day08::solve_part2(INPUT).unwrap().accumulator, 761);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(Day08::stream_part1(&mut INPUT.as_bytes(), &()), Ok(1384));
+        assert_eq!(
+            Day08::stream_part2(&mut INPUT.as_bytes(), &()).map(|repair| repair.line),
+            Ok(193)
+        );
+        assert_eq!(
+            Day08::stream_part1(&mut "nop +0\n\nmul +2".as_bytes(), &()),
+            Err(Error::parse(3, 1, "unknown operation \"mul\""))
+        );
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        let mut session = Day08::explore(&input).unwrap();
+        let mut execute = |line: &str| {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            session.execute(words[0], &words[1..]).unwrap()
+        };
+
+        assert_eq!(
+            execute("step 2"),
+            "   0: nop +0     acc 0\n   1: acc +1     acc 1\n\
+             ip 2, acc 1, 2 instructions executed"
+        );
+        assert_eq!(
+            execute("run"),
+            "ip 1, acc 5, 7 instructions executed, the next instruction was executed before"
+        );
+        assert_eq!(execute("list 6 2"), " *    6: acc +1\n *    7: jmp -4");
+
+        assert_eq!(execute("flip 7"), "   7: nop -4");
+        execute("reset");
+        assert_eq!(
+            execute("run"),
+            "ip 9, acc 8, 6 instructions executed, the program has ended"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day08::solve_part1("nop +0\nmul +3").unwrap_err(),
+            Error::parse(2, 1, "unknown operation \"mul\"")
+        );
+        assert_eq!(
+            Day08::solve_part1("nop +0\njmp").unwrap_err(),
+            Error::parse(2, 4, "missing argument")
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{collections::VecDeque, fmt, io::BufRead};
+
+use aoc_core::{
+    error,
+    generate::{retry, Generated, Rng, StdRng},
+    normalize, params, stream, trace, Answer, Error, Result, Solution,
+};
+use serde_json::{json, Value};
+
+pub struct Day09;
+
+params! {
+    pub struct Params {
+        /// Number of preceding numbers each number has to be the sum of two of
+        window: usize = 25,
+    }
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    type Input<'a> = Vec<u64>;
+    type Params = Params;
+    type Output1 = u64;
+    type Output2 = Weakness;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        find_invalid_number(input, params.window)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        let invalid = find_invalid_number(input, params.window)?;
+        find_contiguous_range(input, invalid).map(|(min, max)| Weakness { min, max })
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        find_invalid_streaming(stream_numbers(reader), params.window)
+    }
+
+    /// The range may lie anywhere in the input, so all numbers are kept
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        let input = stream_numbers(reader).collect::<Result<_>>()?;
+        Self::part2(&input, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Smallest and largest number of the contiguous range, the answer is their sum
+#[derive(Debug, PartialEq, Eq)]
+pub struct Weakness {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl fmt::Display for Weakness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.min + self.max)
+    }
+}
+
+impl Answer for Weakness {
+    fn details(&self) -> Option<Value> {
+        Some(json!({ "min": self.min, "max": self.max }))
+    }
+}
+
+/// Finds the first number which is not the sum of two of the `window` numbers before it
+pub fn find_invalid_number(numbers: &[u64], window: usize) -> Result<u64> {
+    let mut start = 0;
+
+    while start + window < numbers.len() {
+        let n_to_check = numbers[start + window];
+        let preamble = &numbers[start..start + window];
+
+        if !check_preamble_contains(preamble, n_to_check) {
+            return Ok(n_to_check);
+        }
+        start += 1;
+    }
+
+    Err(Error::NoSolution)
+}
+
+/// Finds the invalid number while reading the numbers, keeping only the last `window` of them
+pub fn find_invalid_streaming(
+    numbers: impl IntoIterator<Item = Result<u64>>,
+    window: usize,
+) -> Result<u64> {
+    let mut preamble = VecDeque::with_capacity(window + 1);
+
+    for n in numbers {
+        let n = n?;
+        if preamble.len() == window {
+            if !check_preamble_contains(preamble.make_contiguous(), n) {
+                return Ok(n);
+            }
+            preamble.pop_front();
+        }
+        preamble.push_back(n);
+    }
+
+    Err(Error::NoSolution)
+}
+
+fn check_preamble_contains(preamble: &[u64], n: u64) -> bool {
+    trace::count("numbers checked");
+    for x in preamble {
+        if *x > n {
+            continue;
+        }
+
+        let n_to_find = n - *x;
+        if preamble.contains(&n_to_find) && n_to_find != *x {
+            return true;
+        }
+    }
+    false
+}
+
+/// Finds a contiguous range of numbers summing to `n` and returns its smallest and largest number
+pub fn find_contiguous_range(numbers: &[u64], n: u64) -> Result<(u64, u64)> {
+    let mut start = 0;
+    let mut end = 1;
+
+    while end < numbers.len() {
+        let slice = &numbers[start..end];
+        let sum: u64 = slice.iter().sum();
+        trace::count("ranges summed");
+
+        match sum.cmp(&n) {
+            std::cmp::Ordering::Equal => {
+                return Ok((*slice.iter().min().unwrap(), *slice.iter().max().unwrap()));
+            }
+            std::cmp::Ordering::Greater => {
+                start += 1;
+                end = start + 1;
+                continue;
+            }
+            std::cmp::Ordering::Less => end += 1,
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u64>> {
+    normalize::lines(input)
+        .map(|x| error::parse(input, x))
+        .collect()
+}
+
+fn stream_numbers(reader: &mut dyn BufRead) -> impl Iterator<Item = Result<u64>> + '_ {
+    stream::lines(reader).map(|line| line?.parse(error::parse))
+}
+
+/// Generates numbers which are each the sum of two different numbers among the previous 25, except
+/// for one which is instead the sum of a contiguous range of earlier numbers
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const WINDOW: usize = 25;
+
+    retry(rng, |rng| {
+        let invalid_at = rng.gen_range(200..350);
+        let mut numbers = (0..WINDOW)
+            .map(|_| rng.gen_range(1..100))
+            .collect::<Vec<u64>>();
+
+        while numbers.len() < 400 {
+            let window = &numbers[numbers.len() - WINDOW..];
+
+            if numbers.len() == invalid_at {
+                let start = rng.gen_range(invalid_at / 2..invalid_at - WINDOW - 20);
+                let range = &numbers[start..start + rng.gen_range(2..=17)];
+                let sum = range.iter().sum::<u64>();
+                if window
+                    .iter()
+                    .any(|a| window.iter().any(|b| a != b && a + b == sum))
+                {
+                    return None;
+                }
+                numbers.push(sum);
+            } else {
+                let a = window[rng.gen_range(0..WINDOW)];
+                let b = window[rng.gen_range(0..WINDOW)];
+                if a == b {
+                    continue;
+                }
+                numbers.push(a.checked_add(b)?);
+            }
+        }
+
+        // The planted range has to be the only one adding up to the invalid number
+        let invalid = numbers[invalid_at];
+        let mut ranges = (0..numbers.len()).flat_map(|start| {
+            let numbers = &numbers;
+            (start + 2..=numbers.len())
+                .map(move |end| &numbers[start..end])
+                .take_while(move |range| range.iter().sum::<u64>() <= invalid)
+                .filter(move |range| range.iter().sum::<u64>() == invalid)
+        });
+        let range = ranges.next()?;
+        if ranges.next().is_some() || numbers[..invalid_at].contains(&invalid) {
+            return None;
+        }
+        let weakness = range.iter().min()? + range.iter().max()?;
+
+        let lines = numbers.iter().map(u64::to_string).collect::<Vec<_>>();
+        Some(
+            Generated::new(lines.join("\n"))
+                .with_answer(1, invalid)
+                .with_answer(2, weakness),
+        )
+    })
+}
+
+#[cfg(test)]
+mod day09_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{find_contiguous_range, find_invalid_number, parse_input, Day09, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_09.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            find_invalid_number(&parse_input(INPUT).unwrap(), 25).unwrap(),
+            177777905
+        );
+        assert_eq!(Day09::solve_part1(INPUT).unwrap(), 177777905);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (min, max) = find_contiguous_range(&parse_input(INPUT).unwrap(), 177777905).unwrap();
+        assert_eq!(min + max, 23463012);
+        assert_eq!(Day09::solve_part2(INPUT).unwrap().to_string(), "23463012");
+    }
+
+    #[test]
+    fn test_stream() {
+        let params = Params::default();
+        assert_eq!(
+            Day09::stream_part1(&mut INPUT.as_bytes(), &params),
+            Ok(177777905)
+        );
+        assert_eq!(
+            Day09::stream_part2(&mut INPUT.as_bytes(), &params)
+                .map(|weakness| weakness.to_string()),
+            Ok("23463012".to_string())
+        );
+
+        let example = "35 20 15 25 47 40 62 55 65 95 102 117 150 182 127 219".replace(' ', "\n");
+        let params = Params { window: 5 };
+        assert_eq!(
+            Day09::stream_part1(&mut example.as_bytes(), &params),
+            Ok(127)
+        );
+        assert_eq!(
+            Day09::stream_part1(&mut &example.as_bytes()[..9], &params),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn test_window() {
+        let input = "35 20 15 25 47 40 62 55 65 95 102 117 150 182 127 219 299 277 309 576";
+        let input = input.replace(' ', "\n");
+        let params = Params { window: 5 };
+
+        assert_eq!(Day09::solve_part1_with(&input, &params).unwrap(), 127);
+        assert_eq!(
+            Day09::solve_part2_with(&input, &params)
+                .unwrap()
+                .to_string(),
+            "62"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::HashMap, io::BufRead, iter};
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, stream, trace, Error, Result, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    type Input<'a> = Vec<u8>;
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        // The chain starts at the outlet's 0 jolts and ends 3 jolts above the last adapter, in the
+        // device, so only that last difference is counted up front
+        let (ones, threes) =
+            iter::once(&0)
+                .chain(input)
+                .zip(input)
+                .try_fold((0, 1), |(ones, threes), (a, b)| match b - a {
+                    3 => Ok((ones, threes + 1)),
+                    2 => Ok((ones, threes)),
+                    1 => Ok((ones + 1, threes)),
+                    // Either a duplicate adapter or a gap which no adapter can bridge
+                    _ => Err(Error::NoSolution),
+                })?;
+
+        Ok(ones * threes)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let mut distances = HashMap::new();
+        distances.insert(0, 1);
+
+        for x in input {
+            trace::count("adapters");
+            let n_paths = (1..=3)
+                .map(|d| {
+                    x.checked_sub(d)
+                        .and_then(|x| distances.get(&x))
+                        .unwrap_or(&0)
+                })
+                .sum::<u64>();
+            distances.insert(*x, n_paths);
+        }
+
+        distances
+            .get(input.last().ok_or(Error::NoSolution)?)
+            .copied()
+            .ok_or(Error::NoSolution)
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        Self::part1(&stream_adapters(reader)?, params)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        Self::part2(&stream_adapters(reader)?, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// The adapters have to be sorted, so all of them are kept, but not the text they were read from
+fn stream_adapters(reader: &mut dyn BufRead) -> Result<Vec<u8>> {
+    let mut adapters = stream::lines(reader)
+        .map(|line| line?.parse(error::parse))
+        .collect::<Result<Vec<u8>>>()?;
+    adapters.sort_unstable();
+    Ok(adapters)
+}
+
+fn parse_input(input: &str) -> Result<Vec<u8>> {
+    let mut numbers = normalize::lines(input)
+        .map(|l| error::parse(input, l))
+        .collect::<Result<Vec<u8>>>()?;
+    numbers.sort_unstable();
+    Ok(numbers)
+}
+
+/// Generates a chain of adapters differing by 1 or 3 jolts, in runs of at most four differences
+/// of 1 like in the puzzle's inputs.
+///
+/// Such a run of `n` differences of 1 can be arranged in as many ways as there are compositions of
+/// `n` into parts of at most 3, which gives the number of arrangements of the whole chain.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const COMPOSITIONS: [u64; 5] = [1, 1, 2, 4, 7];
+
+    let mut adapters = Vec::new();
+    let mut joltage = 0;
+    let (mut ones, mut threes, mut arrangements) = (0, 1, 1);
+
+    while joltage < 220 {
+        // Longer runs would let the number of arrangements overflow
+        let run = if arrangements < 1 << 40 {
+            rng.gen_range(1..=4)
+        } else {
+            1
+        };
+        for _ in 0..run {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        ones += run;
+        arrangements *= COMPOSITIONS[run as usize];
+
+        joltage += 3;
+        adapters.push(joltage);
+        threes += 1;
+    }
+    adapters.shuffle(rng);
+
+    let lines = adapters.iter().map(u8::to_string).collect::<Vec<_>>();
+    Generated::new(lines.join("\n"))
+        .with_answer(1, ones * threes)
+        .with_answer(2, arrangements)
+}
+
+#[cfg(test)]
+mod day10_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::Day10;
+
+    const INPUT: &str = include_str!("../../inputs/day_10.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day10::solve_part1(INPUT).unwrap(), 2664);
+    }
+
+    #[test]
+    fn test_outlet() {
+        // The gap from the outlet to the first adapter counts like any other
+        assert_eq!(Day10::solve_part1("3\n6"), Ok(0));
+        assert_eq!(Day10::solve_part1("2\n3\n6"), Ok(2));
+        assert_eq!(Day10::solve_part1("4\n5"), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day10::solve_part2(INPUT).unwrap(), 148098383347712);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(Day10::stream_part1(&mut INPUT.as_bytes(), &()), Ok(2664));
+        assert_eq!(
+            Day10::stream_part2(&mut INPUT.as_bytes(), &()),
+            Ok(148098383347712)
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use aoc_core::{
+    generate::{self, Generated, Rng, StdRng},
+    params, trace,
+    visual::{Rgb, Sink},
+    Error, Result, Solution,
+};
+use aoc_grid::{Grid, Point};
+
+pub struct Day11;
+
+params! {
+    pub struct Params {
+        /// Occupied adjacent seats at which people leave their seat in part 1
+        adjacent_tolerance: usize = 4,
+        /// Occupied visible seats at which people leave their seat in part 2
+        visible_tolerance: usize = 5,
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Input<'a> = SeatMap;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        settle_adjacent(input, params.adjacent_tolerance, |_| {}).ok_or(Error::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        settle_visible(input, params.visible_tolerance, |_| {}).ok_or(Error::NoSolution)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    /// Shows every round of the seats settling, occupied seats in red and empty ones in green
+    fn visualise(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        part: u8,
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        let observe = |map: &SeatMap| {
+            sink.frame(map.frame(|ch| match ch {
+                'L' => Rgb::GREEN,
+                '#' => Rgb::RED,
+                _ => Rgb::BLACK,
+            }))
+        };
+
+        match part {
+            1 => settle_adjacent(input, params.adjacent_tolerance, observe),
+            _ => settle_visible(input, params.visible_tolerance, observe),
+        }
+        .map(|_| ())
+        .ok_or(Error::NoSolution)
+    }
+}
+
+/// Floor `.`, empty seats `L` and occupied seats `#`
+pub type SeatMap = Grid<char>;
+
+pub fn parse_input(input: &str) -> Result<SeatMap> {
+    Grid::parse(input, |ch| match ch {
+        '.' | 'L' | '#' => Some(ch),
+        _ => None,
+    })
+}
+
+/// Lets the seats settle, where people only look at the directly adjacent seats
+fn settle_adjacent(
+    map: &SeatMap,
+    tolerance: usize,
+    observe: impl FnMut(&SeatMap),
+) -> Option<usize> {
+    settle(
+        map,
+        tolerance,
+        |map, seat| map.neighbours8(seat).filter(|(_, ch)| **ch == '#').count(),
+        observe,
+    )
+}
+
+/// Lets the seats settle, where people look at the first seat they can see in each direction
+fn settle_visible(map: &SeatMap, tolerance: usize, observe: impl FnMut(&SeatMap)) -> Option<usize> {
+    settle(
+        map,
+        tolerance,
+        |map, seat| {
+            Point::ADJACENT
+                .iter()
+                .filter_map(|dir| map.ray(seat, *dir).find(|(_, ch)| **ch != '.'))
+                .filter(|(_, ch)| **ch == '#')
+                .count()
+        },
+        observe,
+    )
+}
+
+const MAX_ROUNDS: usize = 1000;
+
+/// Applies the seating rules until nothing changes and counts the occupied seats.
+///
+/// Empty seats get occupied if `occupied_neighbours` finds none, occupied seats are left if it
+/// finds at least `tolerance`. Returns `None` if the seats keep changing, which shows once they
+/// flip back to how they were two rounds before or after [`MAX_ROUNDS`]. `observe` is called with
+/// the seats before every round.
+fn settle(
+    map: &SeatMap,
+    tolerance: usize,
+    occupied_neighbours: impl Fn(&SeatMap, Point) -> usize,
+    mut observe: impl FnMut(&SeatMap),
+) -> Option<usize> {
+    let mut map = map.clone();
+    let mut previous = None;
+
+    for _ in 0..MAX_ROUNDS {
+        trace::count("rounds");
+        observe(&map);
+        let next = Grid::from_fn(map.width(), map.height(), |point| match map[point] {
+            'L' if occupied_neighbours(&map, point) == 0 => '#',
+            '#' if occupied_neighbours(&map, point) >= tolerance => 'L',
+            ch => ch,
+        });
+
+        if next == map {
+            return Some(map.iter().filter(|(_, ch)| **ch == '#').count());
+        }
+        if previous.as_ref() == Some(&next) {
+            return None;
+        }
+        previous = Some(std::mem::replace(&mut map, next));
+    }
+
+    None
+}
+
+/// Generates a seat layout of the size of the puzzle's, where about every third square is floor.
+///
+/// Some layouts never settle, most of them once there is less floor, so the seats are settled
+/// under both rules to discard these. This is how the answers are found as well, so none are
+/// given.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    generate::retry(rng, |rng| {
+        let input = generate_layout(rng);
+        let map = parse_input(&input).ok()?;
+        let params = Params::default();
+        settle_adjacent(&map, params.adjacent_tolerance, |_| {})?;
+        settle_visible(&map, params.visible_tolerance, |_| {})?;
+        Some(Generated::new(input))
+    })
+}
+
+fn generate_layout(rng: &mut StdRng) -> String {
+    let rows = (0..rng.gen_range(80..=100))
+        .map(|_| {
+            (0..95)
+                .map(|_| if rng.gen_bool(0.3) { '.' } else { 'L' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod day11_test {
+    use aoc_core::{visual::Rgb, Solution};
+
+    use crate::{Day11, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_11.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day11::solve_part1(INPUT).unwrap(), 2481);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day11::solve_part2(INPUT).unwrap(), 2227);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        // Nobody ever leaves a seat with more than eight neighbours, so all seats get occupied
+        let params = Params {
+            adjacent_tolerance: 9,
+            visible_tolerance: 9,
+        };
+        let seats = INPUT.matches('L').count();
+
+        assert_eq!(Day11::solve_part1_with(INPUT, &params).unwrap(), seats);
+        assert_eq!(Day11::solve_part2_with(INPUT, &params).unwrap(), seats);
+    }
+
+    #[test]
+    fn test_visualise() {
+        let input = Day11::parse(INPUT).unwrap();
+        let mut frames = Vec::new();
+        Day11::visualise(&input, &Params::default(), 1, &mut frames).unwrap();
+
+        let last = frames.last().unwrap();
+        assert_eq!(
+            (last.width(), last.height()),
+            (input.width(), input.height())
+        );
+        assert_eq!(
+            last.pixels()
+                .iter()
+                .filter(|pixel| **pixel == Rgb::RED)
+                .count(),
+            2481
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::io::BufRead;
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, StdRng},
+    normalize, stream, trace,
+    visual::{Frame, Rgb, Sink},
+    Error, Result, Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    type Input<'a> = Vec<(char, i32)>;
+    type Params = ();
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let ship = navigate(input, Ship::new(0, 0), false, |_| {});
+
+        Ok(ship.distance())
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let mut ship = Ship::new(0, 0);
+        ship.waypoint = (10, -1);
+        let ship = navigate(input, ship, true, |_| {});
+
+        Ok(ship.distance())
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output1> {
+        let ship = stream_navigate(reader, Ship::new(0, 0), false)?;
+
+        Ok(ship.distance())
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output2> {
+        let mut ship = Ship::new(0, 0);
+        ship.waypoint = (10, -1);
+        let ship = stream_navigate(reader, ship, true)?;
+
+        Ok(ship.distance())
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    /// Draws the path of the ship as it grows, scaled down to fit into [`MAX_FRAME_SIZE`]
+    fn visualise(
+        input: &Self::Input<'_>,
+        _: &Self::Params,
+        part: u8,
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        let mut ship = Ship::new(0, 0);
+        if part == 2 {
+            ship.waypoint = (10, -1);
+        }
+        let mut path = vec![(0, 0)];
+        navigate(input, ship, part == 2, |ship| path.push((ship.x, ship.y)));
+        draw_path(&path, sink);
+
+        Ok(())
+    }
+}
+
+struct Ship {
+    pub x: i32,
+    pub y: i32,
+    pub waypoint: (i32, i32),
+}
+
+impl Ship {
+    pub fn new(x: i32, y: i32) -> Self {
+        Ship {
+            x,
+            y,
+            waypoint: (1, 0),
+        }
+    }
+
+    /// Manhattan distance of the ship from the origin
+    pub fn distance(&self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Follows a single instruction, `N`, `S`, `E` and `W` move the waypoint instead of the ship
+    /// if `move_waypoint` is set
+    fn follow(&mut self, (action, val): (char, i32), move_waypoint: bool) {
+        let shift = match action {
+            'N' => Some((0, -val)),
+            'S' => Some((0, val)),
+            'E' => Some((val, 0)),
+            'W' => Some((-val, 0)),
+            _ => None,
+        };
+
+        match (action, shift) {
+            (_, Some((dx, dy))) if move_waypoint => {
+                self.waypoint.0 += dx;
+                self.waypoint.1 += dy;
+            }
+            (_, Some((dx, dy))) => {
+                self.x += dx;
+                self.y += dy;
+            }
+            ('F', None) => {
+                self.x += val * self.waypoint.0;
+                self.y += val * self.waypoint.1;
+            }
+            ('R', None) => {
+                self.waypoint = rotate_2d(self.waypoint.0, self.waypoint.1, val);
+            }
+            ('L', None) => {
+                self.waypoint = rotate_2d(self.waypoint.0, self.waypoint.1, -val);
+            }
+            // The parser only accepts the actions above
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Follows the instructions and returns the ship at its destination, see [`Ship::follow`].
+/// `observe` is called with the ship after every instruction.
+fn navigate(
+    instructions: &[(char, i32)],
+    mut ship: Ship,
+    move_waypoint: bool,
+    mut observe: impl FnMut(&Ship),
+) -> Ship {
+    for &instruction in instructions {
+        ship.follow(instruction, move_waypoint);
+        trace::count("instructions");
+        observe(&ship);
+    }
+    ship
+}
+
+/// Follows the instructions while reading them, one at a time
+fn stream_navigate(reader: &mut dyn BufRead, mut ship: Ship, move_waypoint: bool) -> Result<Ship> {
+    for line in stream::lines(reader) {
+        ship.follow(line?.parse(parse_instruction)?, move_waypoint);
+        trace::count("instructions");
+    }
+    Ok(ship)
+}
+
+fn rotate_2d(x: i32, y: i32, degrees: i32) -> (i32, i32) {
+    (
+        ((x as f32 * (degrees as f32).to_radians().cos())
+            - (y as f32 * (degrees as f32).to_radians().sin()))
+        .round() as i32,
+        ((x as f32 * (degrees as f32).to_radians().sin())
+            + (y as f32 * (degrees as f32).to_radians().cos()))
+        .round() as i32,
+    )
+}
+
+/// Longest side of the frames showing the path of the ship, in pixels
+const MAX_FRAME_SIZE: i64 = 256;
+/// Number of frames the path is drawn in
+const FRAMES: usize = 100;
+
+/// Draws the `path` of the ship in up to [`FRAMES`] steps, marking where it currently is
+fn draw_path(path: &[(i32, i32)], sink: &mut dyn Sink) {
+    let min_x = path.iter().map(|(x, _)| *x as i64).min().unwrap_or(0);
+    let max_x = path.iter().map(|(x, _)| *x as i64).max().unwrap_or(0);
+    let min_y = path.iter().map(|(_, y)| *y as i64).min().unwrap_or(0);
+    let max_y = path.iter().map(|(_, y)| *y as i64).max().unwrap_or(0);
+    let span = (max_x - min_x).max(max_y - min_y).max(1);
+    let scale = |coord: i64, min: i64| ((coord - min) * (MAX_FRAME_SIZE - 1) / span) as isize;
+    let pixel = |(x, y): (i32, i32)| (scale(x as i64, min_x), scale(y as i64, min_y));
+    let (width, height) = (scale(max_x, min_x) + 1, scale(max_y, min_y) + 1);
+
+    let frames = FRAMES.min(path.len());
+    for end in (1..=frames).map(|frame| frame * (path.len() - 1) / frames) {
+        let mut frame = Frame::new(width as usize, height as usize, Rgb::NAVY);
+        for leg in path[..=end].windows(2) {
+            frame.line(pixel(leg[0]), pixel(leg[1]), Rgb::WHITE);
+        }
+        let (start, ship) = (pixel(path[0]), pixel(path[end]));
+        frame.set(start.0, start.1, Rgb::GREEN);
+        frame.set(ship.0, ship.1, Rgb::RED);
+        sink.frame(frame);
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(char, i32)>> {
+    normalize::lines(input)
+        .map(|line| parse_instruction(input, line))
+        .collect()
+}
+
+fn parse_instruction(input: &str, line: &str) -> Result<(char, i32)> {
+    let action = line
+        .chars()
+        .next()
+        .ok_or_else(|| Error::missing(input, line, "action"))?;
+    if !"NSEWLRF".contains(action) {
+        return Err(Error::parse_at(
+            input,
+            line,
+            format!("unknown action {:?}", action),
+        ));
+    }
+    Ok((action, error::parse(input, &line[action.len_utf8()..])?))
+}
+
+/// Generates navigation instructions like the puzzle's, turning by multiples of 90 degrees only,
+/// and follows them with quarter turns in integers to know the answers
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let instructions = (0..rng.gen_range(750..=800))
+        .map(|_| match rng.gen_range(0..7) {
+            0 => ('L', 90 * rng.gen_range(1..=3)),
+            1 => ('R', 90 * rng.gen_range(1..=3)),
+            action => (b"NSEWF"[action - 2] as char, rng.gen_range(1..=100)),
+        })
+        .collect::<Vec<(char, i32)>>();
+
+    // Ship and waypoint of both parts, in the same coordinates as `Ship`
+    let (mut ship1, mut heading) = ((0, 0), (1, 0));
+    let (mut ship2, mut waypoint) = ((0, 0), (10, -1));
+    for &(action, val) in &instructions {
+        let turn = |(x, y): (i32, i32), quarters: i32| {
+            (0..quarters.rem_euclid(4)).fold((x, y), |(x, y), _| (-y, x))
+        };
+        match action {
+            'N' | 'S' | 'E' | 'W' => {
+                let (dx, dy) = match action {
+                    'N' => (0, -val),
+                    'S' => (0, val),
+                    'E' => (val, 0),
+                    _ => (-val, 0),
+                };
+                ship1 = (ship1.0 + dx, ship1.1 + dy);
+                waypoint = (waypoint.0 + dx, waypoint.1 + dy);
+            }
+            'R' => {
+                heading = turn(heading, val / 90);
+                waypoint = turn(waypoint, val / 90);
+            }
+            'L' => {
+                heading = turn(heading, -val / 90);
+                waypoint = turn(waypoint, -val / 90);
+            }
+            _ => {
+                ship1 = (ship1.0 + val * heading.0, ship1.1 + val * heading.1);
+                ship2 = (ship2.0 + val * waypoint.0, ship2.1 + val * waypoint.1);
+            }
+        }
+    }
+
+    let lines = instructions
+        .iter()
+        .map(|(action, val)| format!("{}{}", action, val))
+        .collect::<Vec<_>>();
+    Generated::new(lines.join("\n"))
+        .with_answer(1, ship1.0.abs() + ship1.1.abs())
+        .with_answer(2, ship2.0.abs() + ship2.1.abs())
+}
+
+#[cfg(test)]
+mod day12_test {
+    use aoc_core::{visual::Rgb, Error, Solution};
+
+    use crate::{rotate_2d, Day12};
+
+    const INPUT: &str = include_str!("../../inputs/day_12.txt");
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!((0, 1), rotate_2d(1, 0, 90));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = "F10
+                          N3
+                          F7
+                          R90
+                          F11";
+        assert_eq!(Day12::solve_part1(input).unwrap(), 25);
+        assert_eq!(Day12::solve_part1(INPUT).unwrap(), 2280);
+
+        // Distances count west and north as much as east and south
+        assert_eq!(Day12::solve_part1("W7\nS2").unwrap(), 9);
+        assert_eq!(Day12::solve_part1("N4\nL180\nF3").unwrap(), 7);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day12::solve_part2(INPUT).unwrap(), 38693);
+        assert_eq!(Day12::solve_part2("L180\nF2").unwrap(), 22);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(Day12::stream_part1(&mut INPUT.as_bytes(), &()), Ok(2280));
+        assert_eq!(Day12::stream_part2(&mut INPUT.as_bytes(), &()), Ok(38693));
+        assert_eq!(
+            Day12::stream_part1(&mut "F10\r\nX3".as_bytes(), &()),
+            Err(Error::parse(2, 1, "unknown action 'X'"))
+        );
+    }
+
+    #[test]
+    fn test_visualise() {
+        let input = Day12::parse("F10\nN3\nF7\nR90\nF11").unwrap();
+        let mut frames = Vec::new();
+        Day12::visualise(&input, &(), 1, &mut frames).unwrap();
+
+        // One frame for the start and one per instruction. The path spans 17 units from west to
+        // east and 11 from north to south, so it is scaled to be 256 pixels wide.
+        assert_eq!(frames.len(), 6);
+        let last = frames.last().unwrap();
+        assert_eq!((last.width(), last.height()), (256, 166));
+        assert_eq!(last.get(0, 45), Some(Rgb::GREEN));
+        assert_eq!(last.get(255, 165), Some(Rgb::RED));
+    }
+}
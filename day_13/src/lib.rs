@@ -0,0 +1,181 @@
+use aoc_core::{
+    error,
+    generate::{self, Generated, Rng, SliceRandom, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Shuttle Search";
+
+    type Input<'a> = Notes;
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        input
+            .buses
+            .iter()
+            .map(|(_, bus)| (*bus, *bus - input.timestamp % *bus))
+            .min_by(|a, b| a.1.cmp(&b.1))
+            .map(|(bus, delay)| bus * delay)
+            .ok_or(Error::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        earliest_aligned_departure(&input.buses)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    timestamp: u64,
+    /// Bus IDs together with their offset in the schedule
+    buses: Vec<(u64, u64)>,
+}
+
+/// Finds the earliest timestamp `t` at which every bus departs at `t + offset`.
+///
+/// All bus IDs are prime, so this is the chinese remainder theorem. Sieving is fast enough: once a
+/// bus matches, only multiples of the product of all matched IDs keep matching it. IDs sharing a
+/// factor may have no solution at all, which shows once `bus` steps did not lead to a match.
+fn earliest_aligned_departure(buses: &[(u64, u64)]) -> Result<u64> {
+    let mut timestamp = 0;
+    let mut step = 1;
+
+    for (offset, bus) in buses {
+        let mut attempts = 0;
+        while (timestamp + offset) % bus != 0 {
+            if attempts == *bus {
+                return Err(Error::NoSolution);
+            }
+            timestamp += step;
+            trace::count("timestamps tried");
+            attempts += 1;
+        }
+        step = step / gcd(step, *bus) * bus;
+    }
+
+    Ok(timestamp)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Notes> {
+    let mut lines = normalize::lines(input);
+    let timestamp = lines
+        .next()
+        .ok_or_else(|| Error::missing(input, input, "timestamp"))?;
+    let timestamp = error::parse(input, timestamp)?;
+    let schedule = lines
+        .next()
+        .ok_or_else(|| Error::missing(input, input, "bus schedule"))?;
+    let buses = schedule
+        .split(',')
+        .enumerate()
+        .filter(|(_, bus)| *bus != "x")
+        .map(|(offset, bus)| match error::parse(input, bus)? {
+            0 => Err(Error::parse_at(input, bus, "bus IDs have to be positive")),
+            id => Ok((offset as u64, id)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Notes { timestamp, buses })
+}
+
+/// Generates notes with nine buses of distinct prime IDs, spread over a schedule of `x`s.
+///
+/// Part 2 is constructed backwards: a timestamp is picked first and every bus is placed at an
+/// offset where it departs in time. Notes where two buses would be equally early for part 1 are
+/// discarded.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const PRIMES: [u64; 16] = [
+        13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 73, 79,
+    ];
+
+    generate::retry(rng, |rng| {
+        let buses = PRIMES.choose_multiple(rng, 9).copied().collect::<Vec<_>>();
+        let aligned = buses[0] * rng.gen_range(0..buses[1..].iter().product::<u64>());
+        let timestamp = rng.gen_range(1_000_000..10_000_000);
+
+        // The first bus always has offset 0, the others any later offset matching `aligned`
+        let mut schedule = vec![None; 100];
+        schedule[0] = Some(buses[0]);
+        for &bus in &buses[1..] {
+            let offset = (bus - aligned % bus) % bus;
+            let free = (offset as usize..schedule.len())
+                .step_by(bus as usize)
+                .filter(|offset| *offset > 0 && schedule[*offset].is_none())
+                .collect::<Vec<_>>();
+            schedule[*free.choose(rng)?] = Some(bus);
+        }
+
+        let mut waits = buses
+            .iter()
+            .map(|bus| (bus - timestamp % bus, *bus))
+            .collect::<Vec<_>>();
+        waits.sort_unstable();
+        if waits[0].0 == waits[1].0 || waits[0].0 == waits[0].1 {
+            return None;
+        }
+
+        while schedule.last() == Some(&None) {
+            schedule.pop();
+        }
+        let schedule = schedule
+            .iter()
+            .map(|bus| bus.map_or_else(|| "x".to_string(), |bus| bus.to_string()))
+            .collect::<Vec<_>>();
+
+        Some(
+            Generated::new(format!("{}\n{}", timestamp, schedule.join(",")))
+                .with_answer(1, waits[0].0 * waits[0].1)
+                .with_answer(2, aligned),
+        )
+    })
+}
+
+#[cfg(test)]
+mod day13_test {
+    use aoc_core::Solution;
+
+    use crate::Day13;
+
+    const INPUT: &str = include_str!("../../inputs/day_13.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day13::solve_part1("939\n7,13,x,x,59,x,31,19").unwrap(), 295);
+        assert_eq!(Day13::solve_part1(INPUT).unwrap(), 3865);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day13::solve_part2("939\n7,13,x,x,59,x,31,19").unwrap(),
+            1068781
+        );
+        assert_eq!(
+            Day13::solve_part2("0\n1789,37,47,1889").unwrap(),
+            1202161486
+        );
+        assert_eq!(Day13::solve_part2(INPUT).unwrap(), 415579909629976);
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io::BufRead,
+    vec,
+};
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, stream, trace, Error, Result, Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Docking Data";
+
+    type Input<'a> = Vec<Operation<'a>>;
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        Ok(run::<DecoderV1>(input))
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        Ok(run::<DecoderV2>(input))
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output1> {
+        stream_run::<DecoderV1>(reader)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output2> {
+        stream_run::<DecoderV2>(reader)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Version of the decoder chip, which executes the program one operation at a time
+trait Decoder: Default {
+    fn execute(&mut self, operation: Operation<'_>);
+    /// Sum of all values left in memory
+    fn sum(&self) -> u64;
+}
+
+/// The first version applies masks to the values written
+#[derive(Default)]
+struct DecoderV1 {
+    memory: HashMap<u64, u64>,
+    and_mask: u64,
+    or_mask: u64,
+}
+
+impl Decoder for DecoderV1 {
+    fn execute(&mut self, operation: Operation<'_>) {
+        match operation {
+            Operation::Mask(m) => {
+                self.and_mask = u64::from_str_radix(&m.replace("X", "1"), 2).unwrap();
+                self.or_mask = u64::from_str_radix(&m.replace("X", "0"), 2).unwrap();
+            }
+            Operation::Assignment((address, value)) => {
+                self.memory
+                    .insert(address, value & self.and_mask | self.or_mask);
+            }
+        }
+    }
+
+    fn sum(&self) -> u64 {
+        self.memory.values().sum()
+    }
+}
+
+/// The second version applies masks to the addresses written to, floating bits taking both values
+#[derive(Default)]
+struct DecoderV2 {
+    memory: HashMap<u64, u64>,
+    mask_variations: Vec<(u64, u64)>,
+}
+
+impl Decoder for DecoderV2 {
+    fn execute(&mut self, operation: Operation<'_>) {
+        match operation {
+            Operation::Mask(m) => {
+                self.mask_variations = find_mask_variations(m);
+            }
+            Operation::Assignment((address, value)) => {
+                trace::count_by("memory writes", self.mask_variations.len() as u64);
+                for (or_mask, and_mask) in &self.mask_variations {
+                    self.memory.insert(address & and_mask | or_mask, value);
+                }
+            }
+        }
+    }
+
+    fn sum(&self) -> u64 {
+        self.memory.values().sum()
+    }
+}
+
+fn run<D: Decoder>(program: &[Operation<'_>]) -> u64 {
+    let mut decoder = D::default();
+    for &operation in program {
+        decoder.execute(operation);
+    }
+    decoder.sum()
+}
+
+/// Executes the operations while reading them, only the memory written is kept
+fn stream_run<D: Decoder>(reader: &mut dyn BufRead) -> Result<u64> {
+    let mut decoder = D::default();
+    for line in stream::lines(reader) {
+        decoder.execute(line?.parse(parse_operation)?);
+    }
+    Ok(decoder.sum())
+}
+
+fn find_mask_variations(mask: &str) -> Vec<(u64, u64)> {
+    let mut queue = VecDeque::new();
+    let mut masks = vec![];
+
+    queue.push_back(mask.replace('0', "N").replace('1', "E"));
+
+    while let Some(remainder) = queue.pop_front() {
+        trace::count("masks expanded");
+        if remainder.contains('X') {
+            let x = remainder.replacen("X", "1", 1);
+            let y = remainder.replacen("X", "0", 1);
+            queue.push_front(x);
+            queue.push_front(y);
+        } else {
+            let or_mask =
+                u64::from_str_radix(&remainder.replace('E', "1").replace('N', "0"), 2).unwrap();
+            let and_mask =
+                u64::from_str_radix(&remainder.replace('E', "0").replace('N', "1"), 2).unwrap();
+            masks.push((or_mask, and_mask));
+        }
+    }
+
+    masks
+}
+
+#[derive(Clone, Copy)]
+pub enum Operation<'a> {
+    Mask(&'a str),
+    Assignment((u64, u64)),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Operation<'_>>> {
+    normalize::lines(input)
+        .map(|line| parse_operation(input, line))
+        .collect()
+}
+
+fn parse_operation<'a>(input: &str, line: &'a str) -> Result<Operation<'a>> {
+    let (target, value) = line
+        .split_once(" = ")
+        .ok_or_else(|| Error::parse_at(input, line, "expected \"<target> = <value>\""))?;
+
+    if target == "mask" {
+        if value.len() != 36 || value.contains(|c| !"01X".contains(c)) {
+            return Err(Error::parse_at(
+                input,
+                value,
+                "expected a mask of 36 '0', '1' or 'X'",
+            ));
+        }
+        Ok(Operation::Mask(value))
+    } else {
+        let address = target
+            .strip_prefix("mem[")
+            .and_then(|target| target.strip_suffix(']'))
+            .ok_or_else(|| {
+                Error::parse_at(input, target, "expected \"mask\" or \"mem[<address>]\"")
+            })?;
+        Ok(Operation::Assignment((
+            error::parse(input, address)?,
+            error::parse(input, value)?,
+        )))
+    }
+}
+
+/// Generates a program like the puzzle's, with at most six floating bits per mask
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let mut lines = Vec::new();
+    let mut memory1 = HashMap::new();
+    let mut memory2 = HashMap::new();
+
+    for _ in 0..100 {
+        let mut mask = (0..36)
+            .map(|_| if rng.gen() { b'1' } else { b'0' })
+            .collect::<Vec<_>>();
+        let floating = rng.gen_range(0..=6);
+        for bit in (0..36).collect::<Vec<_>>().choose_multiple(rng, floating) {
+            mask[*bit] = b'X';
+        }
+        let mask = String::from_utf8(mask).unwrap();
+        lines.push(format!("mask = {}", mask));
+
+        let bits = |set: char| {
+            mask.chars()
+                .fold(0, |bits, ch| bits << 1 | (ch == set) as u64)
+        };
+        let (ones, floating) = (bits('1'), bits('X'));
+
+        for _ in 0..rng.gen_range(1..=5) {
+            let (address, value) = (rng.gen_range(0..1 << 16), rng.gen_range(0..1 << 30));
+            lines.push(format!("mem[{}] = {}", address, value));
+
+            memory1.insert(address, value & (ones | floating) | ones);
+
+            // Visits every subset of the floating bits
+            let mut subset = floating;
+            loop {
+                memory2.insert((address | ones) & !floating | subset, value);
+                if subset == 0 {
+                    break;
+                }
+                subset = (subset - 1) & floating;
+            }
+        }
+    }
+
+    Generated::new(lines.join("\n"))
+        .with_answer(1, memory1.values().sum::<u64>())
+        .with_answer(2, memory2.values().sum::<u64>())
+}
+
+#[cfg(test)]
+mod day14_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::Day14;
+
+    const INPUT: &str = include_str!("../../inputs/day_14.txt");
+
+    #[test]
+    fn test_part_1_example() {
+        let input =
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0";
+
+        assert_eq!(Day14::solve_part1(input).unwrap(), 165);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day14::solve_part1(INPUT).unwrap(), 6559449933360);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let example = "mask = 000000000000000000000000000000X1001X\nmem[42] = 100\nmask = 00000000000000000000000000000000X0XX\nmem[26] = 1";
+        assert_eq!(Day14::solve_part2(example).unwrap(), 208);
+
+        assert_eq!(Day14::solve_part2(INPUT).unwrap(), 3369767240513);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            Day14::stream_part1(&mut INPUT.as_bytes(), &()),
+            Ok(6559449933360)
+        );
+        assert_eq!(
+            Day14::stream_part2(&mut INPUT.as_bytes(), &()),
+            Ok(3369767240513)
+        );
+        assert_eq!(
+            Day14::stream_part1(&mut "mem[8] = 11\nmem[x] = 1".as_bytes(), &()),
+            Err(Error::parse(2, 5, "\"x\": invalid digit found in string"))
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, params,
+    repl::{self, Command, Session},
+    trace, Error, Result, Solution,
+};
+
+pub struct Day15;
+
+params! {
+    pub struct Params {
+        /// Turn whose spoken number part 1 asks for
+        part1_turn: usize = 2020,
+        /// Turn whose spoken number part 2 asks for
+        part2_turn: usize = 30_000_000,
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
+
+    type Input<'a> = Vec<usize>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        normalize::trim(input)
+            .split(',')
+            .map(|n| error::parse(input, n))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        play(input, params.part1_turn)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        play(input, params.part2_turn)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    fn explore<'a>(input: &'a Self::Input<'_>) -> Result<Box<dyn Session + 'a>> {
+        Ok(Box::new(GameExplorer {
+            game: Game::new(input)?,
+            starting_numbers: input,
+        }))
+    }
+}
+
+struct SpokenRecord {
+    inner: HashMap<usize, Vec<usize>>,
+}
+
+impl SpokenRecord {
+    pub fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+        }
+    }
+
+    pub fn record_number(&mut self, n: usize, index: usize) {
+        match self.inner.get_mut(&n) {
+            None => {
+                self.inner.insert(n, vec![index]);
+            }
+            Some(indices) => indices.push(index),
+        };
+    }
+
+    pub fn occurences(&self, n: &usize) -> usize {
+        self.inner.get(n).unwrap().len()
+    }
+
+    pub fn last_two(&self, n: &usize) -> (usize, usize) {
+        let mut iter = self.inner.get(n).unwrap().iter().rev();
+        let last = iter.next().unwrap();
+        let second_last = iter.next().unwrap();
+        (*second_last, *last)
+    }
+
+    /// Indices of the turns `n` was spoken on
+    pub fn indices(&self, n: &usize) -> &[usize] {
+        self.inner.get(n).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// The memory game after the starting numbers and any number of turns
+struct Game {
+    spoken: SpokenRecord,
+    index: usize,
+    last_spoken: usize,
+}
+
+impl Game {
+    fn new(input: &[usize]) -> Result<Self> {
+        if input.is_empty() {
+            return Err(Error::invalid_parameter("no starting numbers given"));
+        }
+
+        let mut game = Game {
+            spoken: SpokenRecord::new(),
+            index: 0,
+            last_spoken: 0,
+        };
+        for n in input {
+            game.spoken.record_number(*n, game.index);
+            game.last_spoken = *n;
+            game.index += 1;
+        }
+
+        Ok(game)
+    }
+
+    /// Plays the next turn and returns the number spoken
+    fn speak(&mut self) -> usize {
+        let n_times_spoken = self.spoken.occurences(&self.last_spoken);
+
+        if n_times_spoken == 1 {
+            self.last_spoken = 0;
+        } else {
+            let (second_last, last) = self.spoken.last_two(&self.last_spoken);
+            self.last_spoken = last - second_last;
+        }
+        self.spoken.record_number(self.last_spoken, self.index);
+        self.index += 1;
+
+        self.last_spoken
+    }
+}
+
+/// Plays the memory game until `target_index` numbers have been spoken and returns the last one
+pub fn play(input: &[usize], target_index: usize) -> Result<usize> {
+    if target_index == 0 {
+        return Err(Error::invalid_parameter(
+            "the first spoken number is number 1",
+        ));
+    }
+    if let Some(n) = input.get(target_index - 1) {
+        return Ok(*n);
+    }
+
+    let mut game = Game::new(input)?;
+
+    trace::count_by("turns", (target_index - game.index) as u64);
+    while game.index < target_index {
+        game.speak();
+    }
+
+    Ok(game.last_spoken)
+}
+
+/// Lets the game be played turn by turn, turns are counted from 1 as in the puzzle
+struct GameExplorer<'a> {
+    game: Game,
+    starting_numbers: &'a [usize],
+}
+
+impl GameExplorer<'_> {
+    const COMMANDS: &'static [Command] = &[
+        Command {
+            name: "turn",
+            args: "",
+            help: "Shows the current turn and the number spoken on it",
+        },
+        Command {
+            name: "step",
+            args: "[n]",
+            help: "Plays the next n turns, showing each spoken number",
+        },
+        Command {
+            name: "until",
+            args: "<turn>",
+            help: "Plays up to the turn without showing the numbers in between",
+        },
+        Command {
+            name: "spoken",
+            args: "<number>",
+            help: "Lists the turns the number was spoken on",
+        },
+        Command {
+            name: "reset",
+            args: "",
+            help: "Starts over after the starting numbers",
+        },
+    ];
+
+    fn turn(&self) -> String {
+        format!("turn {}: {}", self.game.index, self.game.last_spoken)
+    }
+}
+
+impl Session for GameExplorer<'_> {
+    fn commands(&self) -> &'static [Command] {
+        Self::COMMANDS
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match command {
+            "turn" => Ok(self.turn()),
+            "step" => {
+                let turns = (0..repl::arg_or(args, 0, "number of turns", 1)?)
+                    .map(|_| {
+                        self.game.speak();
+                        self.turn()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(turns.join("\n"))
+            }
+            "until" => {
+                let turn = repl::arg(args, 0, "turn")?;
+                if turn < self.game.index {
+                    return Err(Error::invalid_parameter(format!(
+                        "turn {} has passed, `reset` starts over",
+                        turn
+                    )));
+                }
+                while self.game.index < turn {
+                    self.game.speak();
+                }
+                Ok(self.turn())
+            }
+            "spoken" => {
+                let n = repl::arg(args, 0, "number")?;
+                let turns = self
+                    .game
+                    .spoken
+                    .indices(&n)
+                    .iter()
+                    .map(|index| (index + 1).to_string())
+                    .collect::<Vec<_>>();
+                if turns.is_empty() {
+                    Ok(format!("{} was not spoken yet", n))
+                } else {
+                    Ok(turns.join(", "))
+                }
+            }
+            "reset" => {
+                self.game = Game::new(self.starting_numbers)?;
+                Ok(self.turn())
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+/// Generates three to seven distinct starting numbers below 20 like the puzzle's.
+///
+/// Only the answer of part 1 is known, as part 2 would take as long to compute as to solve.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let amount = rng.gen_range(3..=7);
+    let numbers = (0..20).collect::<Vec<usize>>();
+    let numbers = numbers
+        .choose_multiple(rng, amount)
+        .copied()
+        .collect::<Vec<_>>();
+
+    // The turn each number was last spoken on, not counting the latest turn
+    let mut last_spoken = vec![None; 2020];
+    for (turn, n) in numbers[..amount - 1].iter().enumerate() {
+        last_spoken[*n] = Some(turn);
+    }
+    let mut spoken = numbers[amount - 1];
+    for turn in amount - 1..2019 {
+        let next = last_spoken[spoken].map_or(0, |last| turn - last);
+        last_spoken[spoken] = Some(turn);
+        spoken = next;
+    }
+
+    let numbers = numbers.iter().map(usize::to_string).collect::<Vec<_>>();
+    Generated::new(numbers.join(",")).with_answer(1, spoken)
+}
+
+#[cfg(test)]
+mod day15_test {
+    use aoc_core::Solution;
+
+    use crate::{play, Day15, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_15.txt");
+
+    #[test]
+    fn test_part_1_examples() {
+        assert_eq!(play(&[0, 3, 6], 2020).unwrap(), 436);
+        assert_eq!(play(&[1, 3, 2], 2020).unwrap(), 1);
+        assert_eq!(play(&[2, 1, 3], 2020).unwrap(), 10);
+        assert_eq!(play(&[1, 2, 3], 2020).unwrap(), 27);
+    }
+
+    #[test]
+    fn test_turns() {
+        let params = Params {
+            part1_turn: 9,
+            part2_turn: 10,
+        };
+        assert_eq!(Day15::solve_part1_with("0,3,6", &params).unwrap(), 4);
+        assert_eq!(Day15::solve_part2_with("0,3,6", &params).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day15::parse("0,3,6").unwrap();
+        let mut session = Day15::explore(&input).unwrap();
+        let mut execute = |line: &str| {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            session.execute(words[0], &words[1..])
+        };
+
+        assert_eq!(execute("turn").unwrap(), "turn 3: 6");
+        assert_eq!(execute("step 2").unwrap(), "turn 4: 0\nturn 5: 3");
+        assert_eq!(execute("until 10").unwrap(), "turn 10: 0");
+        assert_eq!(execute("spoken 0").unwrap(), "1, 4, 8, 10");
+        assert_eq!(execute("spoken 5").unwrap(), "5 was not spoken yet");
+        assert!(execute("until 9").is_err());
+        assert_eq!(execute("reset").unwrap(), "turn 3: 6");
+        assert_eq!(execute("until 2020").unwrap(), "turn 2020: 436");
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day15::solve_part1(INPUT).unwrap(), 240);
+    }
+
+    #[test]
+    fn test_part_2_examples() {
+        assert_eq!(play(&[0, 3, 6], 30_000_000).unwrap(), 175594);
+        assert_eq!(play(&[1, 3, 2], 30_000_000).unwrap(), 2578);
+        assert_eq!(play(&[2, 1, 3], 30_000_000).unwrap(), 3544142);
+        assert_eq!(play(&[1, 2, 3], 30_000_000).unwrap(), 261214);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day15::solve_part2(INPUT).unwrap(), 505);
+    }
+}
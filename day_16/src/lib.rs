@@ -0,0 +1,394 @@
+use std::collections::VecDeque;
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, params, trace, Error, Result, Solution,
+};
+
+pub struct Day16;
+
+params! {
+    pub struct Params {
+        /// Part 2 multiplies the values of your ticket's fields whose names contain this
+        field: String = "departure".into(),
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Ticket Translation";
+
+    type Input<'a> = Notes<'a>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let (rules, _, nearby_tickets) = input;
+        Ok(nearby_tickets
+            .iter()
+            .filter_map(|ticket| {
+                ticket
+                    .iter()
+                    .find(|n| !rules.iter().any(|rule| rule.accepts(n)))
+            })
+            .sum())
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        let (rules, ticket, nearby_tickets) = input;
+
+        let valid_tickets = filter_valid_tickets(nearby_tickets, rules);
+
+        let arrangement = find_valid_rule_arrangement(rules, &valid_tickets)?;
+        Ok(arrangement
+            .iter()
+            .zip(ticket)
+            .filter(|(rule, _)| rule.field.contains(params.field.as_str()))
+            .map(|(_, n)| *n as u64)
+            .product())
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+fn filter_valid_tickets<'a>(tickets: &'a [Ticket], rules: &'a [Rule]) -> Vec<&'a Ticket> {
+    tickets
+        .iter()
+        .filter(|ticket| {
+            ticket
+                .iter()
+                .all(|n| rules.iter().any(|rule| rule.accepts(n)))
+        })
+        .collect::<Vec<_>>()
+}
+
+fn find_valid_rule_arrangement<'a>(
+    rules: &'a [Rule],
+    ticket_pool: &[&Ticket],
+) -> Result<Vec<&'a Rule<'a>>> {
+    let mut queue = VecDeque::new();
+
+    for rule in rules.iter() {
+        queue.push_back(vec![rule]);
+    }
+
+    while let Some(arrangement) = queue.pop_front() {
+        trace::count("arrangements explored");
+        let is_arrangement_valid = ticket_pool.iter().all(|ticket| {
+            arrangement
+                .iter()
+                .zip(ticket.iter())
+                .all(|(rule, n)| rule.accepts(n))
+        });
+
+        if is_arrangement_valid {
+            if arrangement.len() == rules.len() {
+                return Ok(arrangement);
+            }
+
+            for rule in rules {
+                if !arrangement.contains(&rule) {
+                    let mut new_arrangement = arrangement.clone();
+                    new_arrangement.push(rule);
+                    queue.push_front(new_arrangement);
+                }
+            }
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rule<'a> {
+    field: &'a str,
+    rule1: (usize, usize),
+    rule2: (usize, usize),
+}
+
+impl<'a> Rule<'a> {
+    fn accepts(&self, n: &usize) -> bool {
+        (self.rule1.0 <= *n && self.rule1.1 >= *n) || (self.rule2.0 <= *n && self.rule2.1 >= *n)
+    }
+}
+
+type Ticket = Vec<usize>;
+
+/// The rules, your own ticket and all nearby tickets
+pub type Notes<'a> = (Vec<Rule<'a>>, Ticket, Vec<Ticket>);
+
+fn parse_input(input: &str) -> Result<Notes<'_>> {
+    let mut lines = normalize::lines(input).peekable();
+
+    let mut rules = Vec::new();
+    while let Some(line) = lines.next_if(|line| *line != "your ticket:") {
+        rules.push(parse_rule(input, line)?);
+    }
+
+    expect_header(input, lines.next(), "your ticket:")?;
+    let ticket = lines
+        .next()
+        .ok_or_else(|| Error::missing(input, input, "your ticket"))?;
+    let ticket = parse_ticket(input, ticket, rules.len())?;
+
+    expect_header(input, lines.next(), "nearby tickets:")?;
+    let nearby_tickets = lines
+        .map(|line| parse_ticket(input, line, rules.len()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((rules, ticket, nearby_tickets))
+}
+
+fn expect_header(input: &str, line: Option<&str>, header: &str) -> Result<()> {
+    match line {
+        Some(line) if line == header => Ok(()),
+        Some(line) => Err(Error::parse_at(
+            input,
+            line,
+            format!("expected {:?}", header),
+        )),
+        None => Err(Error::missing(input, input, header)),
+    }
+}
+
+fn parse_rule<'a>(input: &str, line: &'a str) -> Result<Rule<'a>> {
+    let (field, ranges) = line
+        .split_once(": ")
+        .ok_or_else(|| Error::parse_at(input, line, "expected \"<field>: <ranges>\""))?;
+    let (rule1, rule2) = ranges
+        .split_once(" or ")
+        .ok_or_else(|| Error::parse_at(input, ranges, "expected \"<range> or <range>\""))?;
+
+    Ok(Rule {
+        field,
+        rule1: parse_range(input, rule1)?,
+        rule2: parse_range(input, rule2)?,
+    })
+}
+
+fn parse_range(input: &str, range: &str) -> Result<(usize, usize)> {
+    let (min, max) = range
+        .split_once('-')
+        .ok_or_else(|| Error::parse_at(input, range, "expected \"<min>-<max>\""))?;
+    Ok((error::parse(input, min)?, error::parse(input, max)?))
+}
+
+fn parse_ticket(input: &str, line: &str, fields: usize) -> Result<Ticket> {
+    let ticket = line
+        .split(',')
+        .map(|n| error::parse(input, n))
+        .collect::<Result<Ticket>>()?;
+
+    if ticket.len() != fields {
+        return Err(Error::parse_at(
+            input,
+            line,
+            format!("expected {} values, found {}", fields, ticket.len()),
+        ));
+    }
+
+    Ok(ticket)
+}
+
+/// Generates notes with ten fields, six of which start with "departure".
+///
+/// The values between 25 and 974 are split into eleven consecutive bands and field `k` accepts
+/// bands `k` and `k + 1`. Every column holds values of both bands of its field, so it matches no
+/// other field. About every fourth nearby ticket gets a value outside of all bands.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const DEPARTURES: [&str; 6] = ["location", "station", "platform", "track", "date", "time"];
+    const OTHERS: [&str; 10] = [
+        "arrival location",
+        "class",
+        "duration",
+        "price",
+        "route",
+        "row",
+        "seat",
+        "train",
+        "type",
+        "wagon",
+    ];
+    const FIELDS: usize = 10;
+    let (min, max) = (25, 974);
+
+    let mut names = DEPARTURES
+        .iter()
+        .map(|name| format!("departure {}", name))
+        .chain(
+            OTHERS
+                .choose_multiple(rng, FIELDS - DEPARTURES.len())
+                .map(|name| name.to_string()),
+        )
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+
+    let mut cuts = (min + 1..=max)
+        .collect::<Vec<_>>()
+        .choose_multiple(rng, FIELDS)
+        .copied()
+        .collect::<Vec<_>>();
+    cuts.sort_unstable();
+    let bands = std::iter::once(min)
+        .chain(cuts.iter().copied())
+        .zip(cuts.iter().map(|cut| cut - 1).chain(std::iter::once(max)))
+        .collect::<Vec<(usize, usize)>>();
+
+    let mut lines = (0..FIELDS)
+        .map(|field| {
+            let (first, second) = (bands[field], bands[field + 1]);
+            format!(
+                "{}: {}-{} or {}-{}",
+                names[field], first.0, first.1, second.0, second.1
+            )
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    // The field of every column and a ticket with a value of band `k + band` for field `k`
+    let mut columns = (0..FIELDS).collect::<Vec<_>>();
+    columns.shuffle(rng);
+    let ticket = |rng: &mut StdRng, band: Option<usize>| {
+        columns
+            .iter()
+            .map(|field| {
+                let (lo, hi) = bands[field + band.unwrap_or_else(|| rng.gen_range(0..2))];
+                rng.gen_range(lo..=hi)
+            })
+            .collect::<Vec<_>>()
+    };
+    let join = |ticket: &[usize]| {
+        let values = ticket.iter().map(usize::to_string).collect::<Vec<_>>();
+        values.join(",")
+    };
+
+    let yours = ticket(rng, None);
+    let departures = columns
+        .iter()
+        .zip(&yours)
+        .filter(|(field, _)| names[**field].starts_with("departure"))
+        .map(|(_, value)| *value as u64)
+        .product::<u64>();
+
+    let mut nearby = vec![ticket(rng, Some(0)), ticket(rng, Some(1))];
+    let mut error_rate = 0;
+    for _ in 0..rng.gen_range(180..=240) {
+        let mut ticket = ticket(rng, None);
+        if rng.gen_bool(0.25) {
+            let invalid = if rng.gen() {
+                rng.gen_range(0..min)
+            } else {
+                rng.gen_range(max + 1..1000)
+            };
+            ticket[rng.gen_range(0..FIELDS)] = invalid;
+            error_rate += invalid;
+        }
+        nearby.push(ticket);
+    }
+    nearby.shuffle(rng);
+
+    lines.push(String::new());
+    lines.push("your ticket:".to_string());
+    lines.push(join(&yours));
+    lines.push(String::new());
+    lines.push("nearby tickets:".to_string());
+    lines.extend(nearby.iter().map(|ticket| join(ticket)));
+
+    Generated::new(lines.join("\n"))
+        .with_answer(1, error_rate)
+        .with_answer(2, departures)
+}
+
+#[cfg(test)]
+mod day16_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{
+        filter_valid_tickets, find_valid_rule_arrangement, parse_input, Day16, Params, Rule,
+    };
+
+    const INPUT: &str = include_str!("../../inputs/day_16.txt");
+    const EXAMPLE_1: &str = include_str!("../../examples/day_16/example_1.txt");
+    const EXAMPLE_2: &str = include_str!("../../examples/day_16/example_2.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day16::solve_part1(INPUT).unwrap(), 24980);
+    }
+
+    #[test]
+    fn test_filter_valid_tickets() {
+        let (rules, _, nearby_tickets) = parse_input(EXAMPLE_1).unwrap();
+        let valid_tickets = filter_valid_tickets(&nearby_tickets, &rules);
+
+        assert_eq!(valid_tickets.len(), 1);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let (rules, _ticket, nearby_tickets) = parse_input(EXAMPLE_2).unwrap();
+        let valid_tickets = filter_valid_tickets(&nearby_tickets, &rules);
+        let arrangement = find_valid_rule_arrangement(&rules, &valid_tickets).unwrap();
+
+        assert_eq!(
+            arrangement,
+            vec![
+                &Rule {
+                    field: "row",
+                    rule1: (0, 5),
+                    rule2: (8, 19)
+                },
+                &Rule {
+                    field: "class",
+                    rule1: (0, 1),
+                    rule2: (4, 19)
+                },
+                &Rule {
+                    field: "seat",
+                    rule1: (0, 13),
+                    rule2: (16, 19)
+                }
+            ]
+        );
+
+        let field = |field: &str| Params {
+            field: field.into(),
+        };
+        assert_eq!(
+            Day16::solve_part2_with(EXAMPLE_2, &field("row")).unwrap(),
+            11
+        );
+        assert_eq!(
+            Day16::solve_part2_with(EXAMPLE_2, &field("s")).unwrap(),
+            12 * 13
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "class: 1-3 or 5-7\nrow: 6-11 33-44\n\nyour ticket:\n7,1";
+        assert_eq!(
+            parse_input(input).unwrap_err(),
+            Error::parse(2, 6, "expected \"<range> or <range>\"")
+        );
+
+        let input = "class: 1-3 or 5-7\nrow: 6-11 or 33-44\n\nyour ticket:\n7,1,14";
+        assert_eq!(
+            parse_input(input).unwrap_err(),
+            Error::parse(5, 1, "expected 2 values, found 3")
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day16::solve_part2(INPUT).unwrap(), 809376774329);
+    }
+}
@@ -0,0 +1,203 @@
+use aoc_core::{
+    generate::{Generated, Rng, StdRng},
+    params, trace,
+    visual::{Frame, Rgb, Sink},
+    Result, Solution,
+};
+use aoc_grid::{Grid, Point, PointN, SparseGrid};
+
+pub struct Day17;
+
+params! {
+    pub struct Params {
+        /// Number of boot cycles
+        cycles: usize = 6,
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    type Input<'a> = SparseGrid<Point>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        Ok(simulate::<3>(input, params.cycles, |_| {}))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        Ok(simulate::<4>(input, params.cycles, |_| {}))
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    /// Shows the slices of the pocket dimension after every cycle
+    fn visualise(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        part: u8,
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        let bounds = input.bounds().unwrap_or((Point::ORIGIN, Point::ORIGIN));
+        let cycles = params.cycles;
+
+        match part {
+            1 => simulate::<3>(input, cycles, |cubes| {
+                sink.frame(draw_slices(cubes, bounds, cycles))
+            }),
+            _ => simulate::<4>(input, cycles, |cubes| {
+                sink.frame(draw_slices(cubes, bounds, cycles))
+            }),
+        };
+
+        Ok(())
+    }
+}
+
+/// Runs the boot cycles in `N` dimensions and returns the number of active cubes afterwards.
+/// `observe` is called with the active cubes before the first and after every cycle.
+fn simulate<const N: usize>(
+    slice: &SparseGrid<Point>,
+    cycles: usize,
+    mut observe: impl FnMut(&SparseGrid<PointN<N>>),
+) -> usize {
+    let mut cubes = SparseGrid::from_points(slice.points().map(PointN::<N>::from_2d));
+    observe(&cubes);
+
+    for _ in 0..cycles {
+        let _span = trace::span("cycle");
+        cubes = cubes.step(PointN::neighbours, |active, neighbours| {
+            neighbours == 3 || (active && neighbours == 2)
+        });
+        observe(&cubes);
+    }
+
+    cubes.len()
+}
+
+/// Draws the active cubes slice by slice, with `z` growing from left to right and, in four
+/// dimensions, `w` growing from top to bottom.
+///
+/// Cubes only spread by one per cycle, so each slice spans the `bounds` of the initial slice
+/// plus the number of `cycles` in every direction.
+fn draw_slices<const N: usize>(
+    cubes: &SparseGrid<PointN<N>>,
+    bounds: (Point, Point),
+    cycles: usize,
+) -> Frame {
+    let margin = cycles as isize;
+    let (min, max) = bounds;
+    let (width, height) = (
+        max.x - min.x + 1 + 2 * margin,
+        max.y - min.y + 1 + 2 * margin,
+    );
+    let columns = 2 * margin + 1;
+    let (rows, row_offset) = if N > 3 { (columns, margin) } else { (1, 0) };
+
+    // Slices are separated by a line of black pixels
+    let mut frame = Frame::from_fn(
+        ((width + 1) * columns - 1) as usize,
+        ((height + 1) * rows - 1) as usize,
+        |x, y| {
+            if (x as isize + 1) % (width + 1) == 0 || (y as isize + 1) % (height + 1) == 0 {
+                Rgb::BLACK
+            } else {
+                Rgb::NAVY
+            }
+        },
+    );
+
+    for cube in cubes.points() {
+        let [x, y, z, w] = [0, 1, 2, 3].map(|axis| cube.0.get(axis).copied().unwrap_or(0));
+        frame.set(
+            (z + margin) * (width + 1) + x - min.x + margin,
+            (w + row_offset) * (height + 1) + y - min.y + margin,
+            Rgb::YELLOW,
+        );
+    }
+
+    frame
+}
+
+/// Parses the initial slice, returning its active cubes
+fn parse_input(input: &str) -> Result<SparseGrid<Point>> {
+    let grid = Grid::parse(input, |ch| match ch {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+
+    Ok(SparseGrid::from_grid(&grid, |active| active.then_some(())))
+}
+
+/// Generates an initial slice of eight by eight cubes, about half of them active. The answers are
+/// only known by running the boot cycles, so none are given.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let rows = (0..8)
+        .map(|_| {
+            (0..8)
+                .map(|_| if rng.gen() { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    Generated::new(rows.join("\n"))
+}
+
+#[cfg(test)]
+mod da17_test {
+    use aoc_core::{visual::Rgb, Solution};
+
+    use crate::{Day17, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_17.txt");
+
+    #[test]
+    fn test_part_1_example() {
+        let input = ".#.
+                          ..#
+                          ###";
+
+        assert_eq!(Day17::solve_part1(input).unwrap(), 112);
+
+        let cycles = |cycles| Params { cycles };
+        assert_eq!(Day17::solve_part1_with(input, &cycles(0)).unwrap(), 5);
+        assert_eq!(Day17::solve_part1_with(input, &cycles(1)).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day17::solve_part1(INPUT).unwrap(), 215);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day17::solve_part2(INPUT).unwrap(), 1728);
+    }
+
+    #[test]
+    fn test_visualise() {
+        let input = Day17::parse(INPUT).unwrap();
+        let mut frames = Vec::new();
+        Day17::visualise(&input, &Params::default(), 1, &mut frames).unwrap();
+
+        assert_eq!(frames.len(), 7);
+        let last = frames.last().unwrap();
+        assert_eq!(
+            last.pixels()
+                .iter()
+                .filter(|pixel| **pixel == Rgb::YELLOW)
+                .count(),
+            215
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::io::BufRead;
+
+use aoc_core::{
+    generate::{Generated, Rng, StdRng},
+    normalize, stream, trace, Error, Result, Solution,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Operation Order";
+
+    type Input<'a> = Vec<&'a str>;
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        normalize::lines(input)
+            .map(|line| validate_expression(input, line).map(|_| line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        Ok(input.iter().map(|line| shunting_yard(line, false)).sum())
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        Ok(input.iter().map(|line| shunting_yard(line, true)).sum())
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output1> {
+        stream_sum(reader, false)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, _: &Self::Params) -> Result<Self::Output2> {
+        stream_sum(reader, true)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+#[derive(Debug)]
+enum Expression {
+    Operation(char),
+    Value(u64),
+}
+
+fn reverse_polish_notation(input: &[Expression]) -> u64 {
+    let mut stack = Vec::new();
+    for expression in input {
+        match expression {
+            Expression::Operation(')') => {}
+            Expression::Operation('(') => {}
+            Expression::Operation(op) => {
+                let left = stack.pop().unwrap();
+                let right = stack.pop().unwrap();
+                let result = match *op {
+                    '+' => left + right,
+                    '*' => left * right,
+                    _ => unreachable!(),
+                };
+                stack.push(result);
+            }
+            Expression::Value(v) => stack.push(*v),
+        }
+    }
+
+    stack.pop().unwrap()
+}
+
+/// Checks that `line`, a slice of `input`, is a well-formed expression of single digits, `+`, `*`
+/// and parentheses, which the evaluation below relies on
+fn validate_expression(input: &str, line: &str) -> Result<()> {
+    let mut depth = 0_usize;
+    let mut expect_operand = true;
+
+    for (idx, ch) in line.char_indices().filter(|(_, ch)| *ch != ' ') {
+        let error = |message: String| Err(Error::parse_at(input, &line[idx..], message));
+
+        match ch {
+            '0'..='9' | '(' if !expect_operand => return error("expected an operator".into()),
+            '+' | '*' | ')' if expect_operand => return error("expected a number".into()),
+            '0'..='9' => expect_operand = false,
+            '(' => depth += 1,
+            '+' | '*' => expect_operand = true,
+            ')' if depth == 0 => return error("unmatched ')'".into()),
+            ')' => depth -= 1,
+            _ => return error(format!("unexpected character {:?}", ch)),
+        }
+    }
+
+    if expect_operand {
+        Err(Error::missing(input, line, "number"))
+    } else if depth > 0 {
+        Err(Error::missing(input, line, "')'"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Sums the values of the expressions while reading them, one at a time
+fn stream_sum(reader: &mut dyn BufRead, addition_before_multiplication: bool) -> Result<u64> {
+    stream::lines(reader).try_fold(0, |sum, line| {
+        let line = line?;
+        line.parse(validate_expression)?;
+        Ok(sum + shunting_yard(line.text(), addition_before_multiplication))
+    })
+}
+
+/// See https://brilliant.org/wiki/shunting-yard-algorithm/
+fn shunting_yard(line: &str, addition_before_multiplication: bool) -> u64 {
+    trace::count("expressions evaluated");
+    let mut operations = Vec::new();
+    let mut output = Vec::new();
+
+    let precedence = match addition_before_multiplication {
+        true => vec!['+'],
+        false => vec!['+', '*'],
+    };
+
+    for ch in line.chars().filter(|c| *c != ' ') {
+        match ch {
+            '(' => operations.push(ch),
+            ')' => loop {
+                match operations.pop() {
+                    Some('(') | None => break,
+                    Some(op) => output.push(Expression::Operation(op)),
+                }
+            },
+            '+' | '*' => {
+                while let Some(op) = operations.pop() {
+                    if precedence.contains(&op) {
+                        output.push(Expression::Operation(op));
+                    } else {
+                        operations.push(op);
+                        break;
+                    }
+                }
+                operations.push(ch);
+            }
+            ch => {
+                output.push(Expression::Value(ch.to_digit(10).unwrap() as u64));
+            }
+        }
+    }
+
+    while let Some(op) = operations.pop() {
+        output.push(Expression::Operation(op));
+    }
+
+    reverse_polish_notation(&output)
+}
+
+/// Generates expressions like the puzzle's, evaluating them under both sets of rules while they
+/// are built
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let expressions = (0..rng.gen_range(360..=380))
+        .map(|_| generate_expression(rng, 0))
+        .collect::<Vec<_>>();
+
+    let lines = expressions
+        .iter()
+        .map(|(line, _, _)| line.as_str())
+        .collect::<Vec<_>>();
+    Generated::new(lines.join("\n"))
+        .with_answer(1, expressions.iter().map(|(_, v, _)| v).sum::<u64>())
+        .with_answer(2, expressions.iter().map(|(_, _, v)| v).sum::<u64>())
+}
+
+/// Generates an expression of two to four operands, which are nested expressions up to `depth`
+/// 1, and returns it with its values from left to right and with addition first.
+///
+/// The values stay below `9^16`, so a few hundred of them can be summed up.
+fn generate_expression(rng: &mut StdRng, depth: usize) -> (String, u64, u64) {
+    let mut line = String::new();
+    let (mut left_to_right, mut products, mut sum) = (0, 1, 0);
+
+    for idx in 0..rng.gen_range(2..=4) {
+        let (operand, value1, value2) = if depth < 1 && rng.gen_bool(0.3) {
+            let (nested, value1, value2) = generate_expression(rng, depth + 1);
+            (format!("({})", nested), value1, value2)
+        } else {
+            let digit = rng.gen_range(1..=9);
+            (digit.to_string(), digit, digit)
+        };
+
+        if idx == 0 {
+            left_to_right = value1;
+            sum = value2;
+        } else if rng.gen() {
+            line.push_str(" + ");
+            left_to_right += value1;
+            sum += value2;
+        } else {
+            line.push_str(" * ");
+            left_to_right *= value1;
+            products *= sum;
+            sum = value2;
+        }
+        line.push_str(&operand);
+    }
+
+    (line, left_to_right, products * sum)
+}
+
+#[cfg(test)]
+mod day18_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{shunting_yard, Day18};
+
+    const INPUT: &str = include_str!("../../inputs/day_18.txt");
+
+    #[test]
+    fn test_evaluate_line() {
+        assert_eq!(shunting_yard("1 + (2 * 3) + (4 * (5 + 6))", false), 51);
+        assert_eq!(shunting_yard("2 * 3 + (4 * 5)", false), 26);
+        assert_eq!(shunting_yard("5 + (8 * 3 + 9 + 3 * 4 * 3)", false), 437);
+        assert_eq!(
+            shunting_yard("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", false),
+            12240
+        );
+        assert_eq!(
+            shunting_yard("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", false),
+            13632
+        );
+
+        assert_eq!(shunting_yard("1 + (2 * 3) + (4 * (5 + 6))", true), 51);
+        assert_eq!(shunting_yard("2 * 3 + (4 * 5)", true), 46);
+        assert_eq!(shunting_yard("5 + (8 * 3 + 9 + 3 * 4 * 3)", true), 1445);
+        assert_eq!(
+            shunting_yard("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", true),
+            669060
+        );
+        assert_eq!(
+            shunting_yard("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", true),
+            23340
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day18::solve_part1("1 + 2\n2 * (3 + 4").unwrap_err(),
+            Error::parse(2, 11, "missing ')'")
+        );
+        assert_eq!(
+            Day18::solve_part1("1 + 2\n2 * + 4").unwrap_err(),
+            Error::parse(2, 5, "expected a number")
+        );
+        assert_eq!(
+            Day18::solve_part1("12 + 2").unwrap_err(),
+            Error::parse(1, 2, "expected an operator")
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day18::solve_part1(INPUT).unwrap(), 50956598240016);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day18::solve_part2(INPUT).unwrap(), 535809575344339);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            Day18::stream_part1(&mut INPUT.as_bytes(), &()),
+            Ok(50956598240016)
+        );
+        assert_eq!(
+            Day18::stream_part2(&mut INPUT.as_bytes(), &()),
+            Ok(535809575344339)
+        );
+        assert_eq!(
+            Day18::stream_part1(&mut "1 + 2\n\n(3 * 4".as_bytes(), &()),
+            Err(Error::parse(3, 7, "missing ')'"))
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Monster Messages";
+
+    type Input<'a> = (HashMap<usize, Rule>, Vec<&'a str>);
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let (rules, messages) = input;
+        Ok(count_matches(rules.clone(), messages))
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let (rules, messages) = input;
+        if !rules.contains_key(&42) || !rules.contains_key(&31) {
+            return Err(Error::NoSolution);
+        }
+
+        let mut rules = rules.clone();
+        rules.insert(8, Rule::Double(vec![42], vec![42, 8]));
+        rules.insert(11, Rule::Double(vec![42, 31], vec![42, 11, 31]));
+        Ok(count_matches(rules, messages))
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+fn count_matches(rules: HashMap<usize, Rule>, messages: &[&str]) -> usize {
+    let checker = RuleChecker::new(rules);
+    messages
+        .iter()
+        .filter(|m| checker.match_all(m, &mut vec![0]))
+        .count()
+}
+
+struct RuleChecker {
+    rules: HashMap<usize, Rule>,
+}
+
+impl RuleChecker {
+    fn new(rules: HashMap<usize, Rule>) -> Self {
+        Self { rules }
+    }
+
+    fn match_all(&self, message: &str, queue: &mut Vec<usize>) -> bool {
+        trace::count("match_all calls");
+        if queue.is_empty() && message.is_empty() {
+            return true;
+        }
+
+        if queue.is_empty() || message.is_empty() {
+            return false;
+        }
+
+        let next_rule = &self.rules[&queue.pop().unwrap()];
+
+        match next_rule {
+            Rule::Terminal(ch) => self.match_char(message, ch, queue),
+            Rule::Single(seq) => self.match_sequence(message, seq, queue),
+            Rule::Double(seq1, seq2) => {
+                self.match_sequence(message, seq1, &mut queue.clone())
+                    || self.match_sequence(message, seq2, &mut queue.clone())
+            }
+        }
+    }
+
+    fn match_char(&self, message: &str, ch: &char, queue: &mut Vec<usize>) -> bool {
+        match message.chars().next() {
+            Some(c) if c == *ch => self.match_all(&message[c.len_utf8()..], queue),
+            _ => false,
+        }
+    }
+
+    fn match_sequence(&self, message: &str, sequence: &[usize], queue: &mut Vec<usize>) -> bool {
+        sequence
+            .iter()
+            .rev()
+            .for_each(|subrule| queue.push(*subrule));
+        self.match_all(message, queue)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Terminal(char),
+    Single(Vec<usize>),
+    Double(Vec<usize>, Vec<usize>),
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<usize, Rule>, Vec<&str>)> {
+    let mut blocks = normalize::blocks(input);
+    let raw_rules = blocks
+        .next()
+        .map_or_else(Vec::new, |rules| normalize::lines(rules).collect());
+    let messages = blocks.flat_map(normalize::lines).collect::<Vec<_>>();
+
+    let rules = raw_rules
+        .iter()
+        .map(|line| parse_rule(input, line))
+        .collect::<Result<HashMap<_, _>>>()?;
+
+    if !rules.contains_key(&0) {
+        return Err(Error::missing(input, input, "rule 0"));
+    }
+
+    // Matching relies on every referenced rule being defined
+    for line in raw_rules {
+        let references = line
+            .split_once(':')
+            .map_or("", |(_, rem)| rem)
+            .split_whitespace()
+            .filter(|token| *token != "|" && !token.starts_with('"'));
+        for reference in references {
+            if !rules.contains_key(&error::parse(input, reference)?) {
+                return Err(Error::parse_at(
+                    input,
+                    reference,
+                    format!("undefined rule {}", reference),
+                ));
+            }
+        }
+    }
+
+    Ok((rules, messages))
+}
+
+fn parse_rule(input: &str, line: &str) -> Result<(usize, Rule)> {
+    let (id, rem) = line
+        .split_once(':')
+        .ok_or_else(|| Error::parse_at(input, line, "expected \"<id>: <rule>\""))?;
+    let id = error::parse(input, id)?;
+    let rem = rem.trim();
+
+    let rule = if let Some(terminal) = rem.strip_prefix('"').and_then(|rem| rem.strip_suffix('"')) {
+        let mut chars = terminal.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Rule::Terminal(ch),
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    rem,
+                    "expected a single character in quotes",
+                ))
+            }
+        }
+    } else if let Some((left, right)) = rem.split_once(" | ") {
+        Rule::Double(parse_sequence(input, left)?, parse_sequence(input, right)?)
+    } else {
+        Rule::Single(parse_sequence(input, rem)?)
+    };
+
+    Ok((id, rule))
+}
+
+fn parse_sequence(input: &str, sequence: &str) -> Result<Vec<usize>> {
+    sequence
+        .split_whitespace()
+        .map(|id| error::parse(input, id))
+        .collect()
+}
+
+/// Generates rules and messages where rule 42 matches the chunks of a fixed length starting with
+/// `a` and rule 31 the ones starting with `b`.
+///
+/// Rule 0 then matches three chunks `a`, `a`, `b`, and with the loops of part 2 any `m` chunks
+/// starting with `a` followed by `n` starting with `b` where `m > n`. This is checked for every
+/// message by looking at its chunks.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let chunk = rng.gen_range(3..=5);
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        "42: 1 4".to_string(),
+        "31: 2 4".to_string(),
+        format!("4:{}", " 3".repeat(chunk - 1)),
+        "3: 1 | 2".to_string(),
+        "1: \"a\"".to_string(),
+        "2: \"b\"".to_string(),
+    ];
+    rules.shuffle(rng);
+
+    let random = |rng: &mut StdRng, len: usize| {
+        (0..len)
+            .map(|_| if rng.gen() { 'a' } else { 'b' })
+            .collect::<String>()
+    };
+    let messages = (0..rng.gen_range(400..=480))
+        .map(|_| {
+            if rng.gen_bool(0.8) {
+                let (a, b) = (rng.gen_range(1..=5), rng.gen_range(1..=4));
+                (0..a + b)
+                    .map(|idx| {
+                        let first = if idx < a { 'a' } else { 'b' };
+                        format!("{}{}", first, random(rng, chunk - 1))
+                    })
+                    .collect::<String>()
+            } else {
+                let len = rng.gen_range(2 * chunk..=8 * chunk);
+                random(rng, len)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Numbers of leading chunks starting with `a` and of following ones starting with `b`
+    let chunks = |message: &String| {
+        if !message.len().is_multiple_of(chunk) {
+            return None;
+        }
+        let firsts = message.bytes().step_by(chunk).collect::<Vec<_>>();
+        let a = firsts.iter().take_while(|first| **first == b'a').count();
+        if firsts[a..].iter().any(|first| *first != b'b') {
+            return None;
+        }
+        Some((a, firsts.len() - a))
+    };
+    let part1 = messages
+        .iter()
+        .filter(|message| chunks(message) == Some((2, 1)))
+        .count();
+    let part2 = messages
+        .iter()
+        .filter(|message| matches!(chunks(message), Some((a, b)) if a > b && b > 0))
+        .count();
+
+    Generated::new(format!("{}\n\n{}", rules.join("\n"), messages.join("\n")))
+        .with_answer(1, part1)
+        .with_answer(2, part2)
+}
+
+#[cfg(test)]
+mod day19_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::Day19;
+
+    const INPUT: &str = include_str!("../../inputs/day_19.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day19::solve_part1(INPUT).unwrap(), 113);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day19::solve_part1("0: 1 2\n1: \"a\"\n\nab").unwrap_err(),
+            Error::parse(1, 6, "undefined rule 2")
+        );
+        assert_eq!(
+            Day19::solve_part1("0: 1\n1: \"ab\"\n\nab").unwrap_err(),
+            Error::parse(2, 4, "expected a single character in quotes")
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day19::solve_part2(include_str!("../input_2.txt")).unwrap(),
+            253
+        );
+        assert_eq!(Day19::solve_part2(INPUT).unwrap(), 253);
+    }
+}
@@ -0,0 +1,958 @@
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
+
+use aoc_core::{
+    error,
+    generate::{self, Generated, Rng, SliceRandom, StdRng},
+    normalize,
+    repl::{self, Command, Session},
+    trace,
+    visual::{Frame, Rgb, Sink},
+    Error, Result, Solution,
+};
+use aoc_grid::{Grid, Point};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Jurassic Jigsaw";
+
+    type Input<'a> = Vec<Tile>;
+    type Params = ();
+    type Output1 = u64;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_tiles(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let tiles_done = find_tile_orientations(input, |_| {})?;
+
+        // Find corners
+        let mut min_x = 0;
+        let mut min_y = 0;
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (x, y, _) in tiles_done.iter() {
+            min_x = min(min_x, *x);
+            min_y = min(min_y, *y);
+            max_x = max(max_x, *x);
+            max_y = max(max_y, *y);
+        }
+
+        let result = tiles_done
+            .iter()
+            .filter(|(x, y, _)| [min_x, max_x].contains(x) && [min_y, max_y].contains(y))
+            .map(|(_, _, tile)| tile.id as u64)
+            .product();
+
+        Ok(result)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let image = orient_image(&assemble_image(input)?)?;
+
+        let roughness = image.image.iter().filter(|(_, ch)| **ch == '#').count();
+        let score = roughness - image.count_sea_monsters() * SEA_MONSTER.len();
+
+        Ok(score)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    fn explore<'a>(input: &'a Self::Input<'_>) -> Result<Box<dyn Session + 'a>> {
+        Ok(Box::new(TileExplorer { tiles: input }))
+    }
+
+    /// Part 1 shows the tiles being put together, the corners in yellow. Part 2 shows the
+    /// assembled image and then the sea monsters found in it, in red.
+    fn visualise(
+        input: &Self::Input<'_>,
+        _: &Self::Params,
+        part: u8,
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        let water = |ch: &char| if *ch == '#' { Rgb::BLUE } else { Rgb::NAVY };
+
+        if part == 2 {
+            let image = assemble_image(input)?;
+            sink.frame(image.image.frame(water));
+
+            let image = orient_image(&image)?;
+            let mut frame = image.image.frame(water);
+            for monster in image.sea_monsters() {
+                for (dx, dy) in SEA_MONSTER.iter() {
+                    frame.set(monster.x + dx, monster.y + dy, Rgb::RED);
+                }
+            }
+            sink.frame(frame);
+
+            return Ok(());
+        }
+
+        let mut placed = Vec::new();
+        find_tile_orientations(input, |tile| placed.push(tile.clone()))?;
+
+        // Tiles further west have a larger x and tiles further north a larger y
+        let min_x = placed.iter().map(|(x, _, _)| *x).min().unwrap_or(0);
+        let max_x = placed.iter().map(|(x, _, _)| *x).max().unwrap_or(0);
+        let min_y = placed.iter().map(|(_, y, _)| *y).min().unwrap_or(0);
+        let max_y = placed.iter().map(|(_, y, _)| *y).max().unwrap_or(0);
+        let is_corner = |x, y| [min_x, max_x].contains(&x) && [min_y, max_y].contains(&y);
+        let size = input.first().map_or(0, |tile| tile.image.width() as isize);
+
+        let mut frame = Frame::new(
+            ((max_x - min_x + 1) * (size + 1)) as usize,
+            ((max_y - min_y + 1) * (size + 1)) as usize,
+            Rgb::BLACK,
+        );
+        for (x, y, tile) in &placed {
+            let origin = Point::new((max_x - x) * (size + 1), (max_y - y) * (size + 1));
+            for (point, ch) in tile.image.iter() {
+                let color = match (*ch, is_corner(*x, *y)) {
+                    ('#', true) => Rgb::YELLOW,
+                    _ => water(ch),
+                };
+                frame.set(origin.x + point.x, origin.y + point.y, color);
+            }
+            sink.frame(frame.clone());
+        }
+
+        Ok(())
+    }
+}
+
+/// Shows the tiles in any orientation and how they fit together
+struct TileExplorer<'a> {
+    tiles: &'a [Tile],
+}
+
+impl TileExplorer<'_> {
+    const COMMANDS: &'static [Command] = &[
+        Command {
+            name: "tiles",
+            args: "",
+            help: "Lists the ids of all tiles",
+        },
+        Command {
+            name: "tile",
+            args: "<id> [orientation]",
+            help: "Shows a tile rotated left 0 to 3 times, or 4 to 7 times after flipping it",
+        },
+        Command {
+            name: "edges",
+            args: "<id>",
+            help: "Shows the top, right, bottom and left edge of a tile",
+        },
+        Command {
+            name: "neighbours",
+            args: "<id>",
+            help: "Lists the tiles which fit to a side of a tile, in some orientation",
+        },
+        Command {
+            name: "layout",
+            args: "",
+            help: "Puts the tiles together and shows their ids, as in part 1",
+        },
+        Command {
+            name: "monsters",
+            args: "",
+            help: "Counts the sea monsters in each orientation of the image, as in part 2",
+        },
+    ];
+
+    fn tile(&self, args: &[&str]) -> Result<&Tile> {
+        let id = repl::arg::<usize>(args, 0, "tile id")?;
+        self.tiles
+            .iter()
+            .find(|tile| tile.id == id)
+            .ok_or_else(|| Error::invalid_parameter(format!("no tile {}", id)))
+    }
+}
+
+impl Session for TileExplorer<'_> {
+    fn commands(&self) -> &'static [Command] {
+        Self::COMMANDS
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match command {
+            "tiles" => {
+                let mut ids = self.tiles.iter().map(|tile| tile.id).collect::<Vec<_>>();
+                ids.sort_unstable();
+                let ids = ids.iter().map(usize::to_string).collect::<Vec<_>>();
+                Ok(format!("{} tiles: {}", ids.len(), ids.join(", ")))
+            }
+            "tile" => {
+                let tile = self.tile(args)?;
+                let orientation = repl::arg_or(args, 1, "orientation", 0)?;
+                let oriented = tile.orientations().into_iter().nth(orientation);
+                let oriented = oriented.ok_or_else(|| {
+                    Error::invalid_parameter("the orientation has to be between 0 and 7")
+                })?;
+                Ok(oriented.to_string().trim_end().to_string())
+            }
+            "edges" => {
+                let tile = self.tile(args)?;
+                Ok(format!(
+                    "top    {}\nright  {}\nbottom {}\nleft   {}",
+                    tile.top(),
+                    tile.right(),
+                    tile.bottom(),
+                    tile.left()
+                ))
+            }
+            "neighbours" => {
+                let tile = self.tile(args)?;
+                let neighbours = self
+                    .tiles
+                    .iter()
+                    .filter(|other| other.id != tile.id)
+                    .filter_map(|other| {
+                        let side = match Tile::matches(tile, &other.orientations()) {
+                            Match::North(_) => "top",
+                            Match::East(_) => "left",
+                            Match::South(_) => "bottom",
+                            Match::West(_) => "right",
+                            Match::None => return None,
+                        };
+                        Some(format!("{:<6} {}", side, other.id))
+                    })
+                    .collect::<Vec<_>>();
+                Ok(neighbours.join("\n"))
+            }
+            "layout" => {
+                let placed = find_tile_orientations(self.tiles, |_| {})?;
+                // Placed tiles count x to the west and y to the north
+                let (xs, ys) = (
+                    placed.iter().map(|(x, _, _)| *x),
+                    placed.iter().map(|(_, y, _)| *y),
+                );
+                let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+                let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+                let rows = (min_y..=max_y)
+                    .rev()
+                    .map(|y| {
+                        let ids = (min_x..=max_x).rev().map(|x| {
+                            placed
+                                .iter()
+                                .find(|(tx, ty, _)| (*tx, *ty) == (x, y))
+                                .map_or("----".to_string(), |(_, _, tile)| tile.id.to_string())
+                        });
+                        ids.collect::<Vec<_>>().join(" ")
+                    })
+                    .collect::<Vec<_>>();
+                Ok(rows.join("\n"))
+            }
+            "monsters" => {
+                let image = assemble_image(self.tiles)?;
+                let counts = image
+                    .orientations()
+                    .iter()
+                    .map(|image| image.count_sea_monsters().to_string())
+                    .collect::<Vec<_>>();
+                Ok(format!("orientations 0 to 7: {}", counts.join(", ")))
+            }
+            _ => Err(repl::unknown(command)),
+        }
+    }
+}
+
+/// Puts the tiles together and joins them into a single image without their borders
+fn assemble_image(tiles: &[Tile]) -> Result<Tile> {
+    let _span = trace::span("assemble image");
+    let trimmed_tiles = find_tile_orientations(tiles, |_| {})?
+        .iter()
+        .map(|(_, _, tile)| tile)
+        .map(|tile| {
+            let mut t = tile.clone();
+            t.flip();
+            t.rotate();
+            t.rotate();
+            t.trim_tile()
+        })
+        .collect::<Vec<_>>();
+
+    Ok(join_tiles(&trimmed_tiles))
+}
+
+/// The orientation of the image which shows the most sea monsters
+fn orient_image(image: &Tile) -> Result<Tile> {
+    let _span = trace::span("orient image");
+    image
+        .orientations()
+        .into_iter()
+        .max_by_key(Tile::count_sea_monsters)
+        .ok_or(Error::NoSolution)
+}
+
+struct OrientationCache {
+    inner: HashMap<usize, Vec<Tile>>,
+}
+
+impl OrientationCache {
+    fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+        }
+    }
+
+    fn get_or_insert(&mut self, tile: &Tile) -> Vec<Tile> {
+        #[allow(clippy::map_entry)]
+        if !self.inner.contains_key(&tile.id) {
+            let orientations = tile.orientations();
+            self.inner.insert(tile.id, orientations);
+        }
+        self.inner.get(&tile.id).unwrap().clone()
+    }
+}
+
+/// Finds the position and orientation of every tile, calling `observe` with each tile as it is
+/// placed
+fn find_tile_orientations(
+    tiles: &[Tile],
+    mut observe: impl FnMut(&(isize, isize, Tile)),
+) -> Result<Vec<(isize, isize, Tile)>> {
+    let mut tiles_todo = tiles.iter().cloned().collect::<VecDeque<_>>();
+    let mut tiles_done = Vec::<(isize, isize, Tile)>::new();
+    // Number of tiles tried in a row without fitting any of them
+    let mut misses = 0;
+
+    let mut orientation_cache = OrientationCache::new();
+
+    while let Some(next_tile) = tiles_todo.pop_front() {
+        trace::count("tiles tried");
+        if misses > tiles_todo.len() {
+            return Err(Error::NoSolution);
+        }
+
+        if tiles_done.is_empty() {
+            tiles_done.push((0, 0, next_tile));
+            observe(&tiles_done[0]);
+            continue;
+        }
+
+        let mut changes = Vec::new();
+        for (x, y, t) in &tiles_done {
+            let orientations_next = orientation_cache.get_or_insert(&next_tile);
+
+            match Tile::matches(t, &orientations_next) {
+                Match::North(oriented_tile) => {
+                    changes.push((*x, y + 1, oriented_tile));
+                }
+                Match::South(oriented_tile) => {
+                    changes.push((*x, y - 1, oriented_tile));
+                }
+                Match::East(oriented_tile) => {
+                    changes.push((x + 1, *y, oriented_tile));
+                }
+                Match::West(oriented_tile) => {
+                    changes.push((x - 1, *y, oriented_tile));
+                }
+                Match::None => {}
+            };
+
+            if !changes.is_empty() {
+                break;
+            }
+        }
+
+        if changes.is_empty() {
+            tiles_todo.push_back(next_tile);
+            misses += 1;
+        } else {
+            for c in changes {
+                observe(&c);
+                tiles_done.push(c);
+            }
+            misses = 0;
+        }
+    }
+
+    tiles_done.sort_by(|a, b| (a.1, b.0).cmp(&(b.1, a.0)));
+    Ok(tiles_done)
+}
+
+enum Match {
+    North(Tile),
+    South(Tile),
+    East(Tile),
+    West(Tile),
+    None,
+}
+
+/// Offsets of the `#` of a sea monster relative to the start of its middle row
+const SEA_MONSTER: [(isize, isize); 15] = [
+    (18, -1),
+    (0, 0),
+    (5, 0),
+    (6, 0),
+    (11, 0),
+    (12, 0),
+    (17, 0),
+    (18, 0),
+    (19, 0),
+    (1, 1),
+    (4, 1),
+    (7, 1),
+    (10, 1),
+    (13, 1),
+    (16, 1),
+];
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: usize,
+    image: Grid<char>,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.image.fmt(f)
+    }
+}
+
+impl Tile {
+    fn orientations(&self) -> Vec<Tile> {
+        self.image
+            .orientations()
+            .into_iter()
+            .map(|image| Tile { id: self.id, image })
+            .collect()
+    }
+
+    fn matches(r: &Tile, other_orientations: &[Tile]) -> Match {
+        for o in other_orientations {
+            if r.bottom().eq(&o.top()) {
+                return Match::South(o.clone());
+            } else if r.top().eq(&o.bottom()) {
+                return Match::North(o.clone());
+            } else if r.left().eq(&o.right()) {
+                return Match::East(o.clone());
+            } else if r.right().eq(&o.left()) {
+                return Match::West(o.clone());
+            }
+        }
+        Match::None
+    }
+
+    fn top(&self) -> String {
+        self.image.row(0).iter().collect()
+    }
+
+    fn bottom(&self) -> String {
+        self.image.row(self.image.height() - 1).iter().collect()
+    }
+
+    fn left(&self) -> String {
+        self.image.column(0).collect()
+    }
+
+    fn right(&self) -> String {
+        self.image.column(self.image.width() - 1).collect()
+    }
+
+    /// Rotates left 90°
+    fn rotate(&mut self) {
+        self.image = self.image.rotate_left();
+    }
+
+    /// Flips horizontally, around the y axis
+    fn flip(&mut self) {
+        self.image = self.image.flip_horizontal();
+    }
+
+    /// Removes the border of the tile
+    fn trim_tile(&self) -> Tile {
+        let (width, height) = (self.image.width(), self.image.height());
+        Tile {
+            id: self.id,
+            image: self.image.sub_grid(Point::new(1, 1), width - 2, height - 2),
+        }
+    }
+
+    fn count_sea_monsters(&self) -> usize {
+        self.sea_monsters().count()
+    }
+
+    /// Start of the middle row of every sea monster in the image
+    fn sea_monsters(&self) -> impl Iterator<Item = Point> + '_ {
+        self.image
+            .points()
+            .filter(move |point| self.is_sea_monster_at(*point))
+    }
+
+    fn is_sea_monster_at(&self, start: Point) -> bool {
+        SEA_MONSTER
+            .iter()
+            .all(|(dx, dy)| self.image.get(start + Point::new(*dx, *dy)) == Some(&'#'))
+    }
+}
+
+impl FromStr for Tile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_tile(s, s)
+    }
+}
+
+/// Parses a single tile, `section` has to be a slice of `input` for error reporting
+fn parse_tile(input: &str, section: &str) -> Result<Tile> {
+    let mut lines = normalize::lines(section);
+
+    let header = lines
+        .next()
+        .ok_or_else(|| Error::missing(input, section, "tile header"))?;
+    let id = header
+        .strip_prefix("Tile ")
+        .and_then(|header| header.strip_suffix(':'))
+        .ok_or_else(|| Error::parse_at(input, header, "expected \"Tile <id>:\""))?;
+    let id = error::parse(input, id)?;
+
+    let rows = &section[section.find('\n').unwrap_or(section.len())..];
+    let image = Grid::parse_in(input, rows, |ch| match ch {
+        '.' | '#' => Some(ch),
+        _ => None,
+    })?;
+    if image.width() != image.height() {
+        return Err(Error::parse_at(
+            input,
+            rows.trim_start(),
+            format!("expected a square tile of width {}", image.width()),
+        ));
+    }
+
+    Ok(Tile { id, image })
+}
+
+/// Joins a square of equally sized tiles, given row by row, into a single image
+fn join_tiles(tiles: &[Tile]) -> Tile {
+    let tileset_width = (tiles.len() as f64).sqrt() as usize;
+    let tile_width = tiles[0].image.width();
+    let width = tileset_width * tile_width;
+
+    let image = Grid::from_fn(width, width, |point| {
+        let (x, y) = (point.x as usize, point.y as usize);
+        let tile = &tiles[(y / tile_width) * tileset_width + x / tile_width];
+        tile.image[Point::new((x % tile_width) as isize, (y % tile_width) as isize)]
+    });
+
+    Tile { id: 0, image }
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Tile>> {
+    let tiles = normalize::blocks(input)
+        .map(|section| parse_tile(input, section))
+        .collect::<Result<Vec<_>>>()?;
+    if tiles.is_empty() {
+        return Err(Error::missing(input, input, "tiles"));
+    }
+
+    // The image is assembled from a square of equally sized tiles
+    let tileset_width = (tiles.len() as f64).sqrt() as usize;
+    if tileset_width * tileset_width != tiles.len() {
+        return Err(Error::NoSolution);
+    }
+    let width = tiles.first().map_or(0, |tile| tile.image.width());
+    if let Some(tile) = tiles.iter().find(|tile| tile.image.width() != width) {
+        return Err(Error::parse_at(
+            input,
+            input,
+            format!(
+                "tile {} has width {}, expected {}",
+                tile.id,
+                tile.image.width(),
+                width
+            ),
+        ));
+    }
+
+    Ok(tiles)
+}
+
+/// Generates twelve by twelve tiles of width 10 which assemble into an image with sea monsters.
+///
+/// The image is drawn first, with the monsters planted in one orientation only. The borders
+/// between the tiles are then chosen so that every edge, read in either direction, is shared by
+/// exactly the two tiles it separates, which makes the assembly unique. Finally every tile is
+/// turned into a random orientation and the tiles are shuffled.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const TILES: usize = 12;
+    const INNER: usize = 8;
+    let size = TILES * INNER;
+
+    let (image, monsters) = generate::retry(rng, |rng| {
+        let mut image = Grid::from_fn(size, size, |_| if rng.gen_bool(0.3) { '#' } else { '.' });
+        let mut occupied = HashSet::new();
+        let mut monsters = 0;
+
+        for _ in 0..rng.gen_range(10..=30) {
+            let start = Point::new(
+                rng.gen_range(0..=size - 20) as isize,
+                rng.gen_range(1..size - 1) as isize,
+            );
+            let cells = SEA_MONSTER
+                .iter()
+                .map(|(dx, dy)| start + Point::new(*dx, *dy))
+                .collect::<Vec<_>>();
+            if cells.iter().any(|cell| occupied.contains(cell)) {
+                continue;
+            }
+            for cell in cells {
+                image[cell] = '#';
+                occupied.insert(cell);
+            }
+            monsters += 1;
+        }
+
+        // Discards images where the noise happens to form further monsters
+        let image = Tile { id: 0, image };
+        let found = image
+            .orientations()
+            .iter()
+            .map(Tile::count_sea_monsters)
+            .collect::<Vec<_>>();
+        (found[0] == monsters && found.iter().sum::<usize>() == monsters)
+            .then_some((image.image, monsters))
+    });
+
+    // Every tile is a window of 10 by 10 pixels, sharing its borders with its neighbours
+    let mut pixels = Grid::from_fn(TILES * 9 + 1, TILES * 9 + 1, |point| {
+        if point.x % 9 == 0 || point.y % 9 == 0 {
+            if rng.gen() {
+                '#'
+            } else {
+                '.'
+            }
+        } else {
+            let (x, y) = (point.x as usize, point.y as usize);
+            image[Point::new(
+                (x / 9 * INNER + x % 9 - 1) as isize,
+                (y / 9 * INNER + y % 9 - 1) as isize,
+            )]
+        }
+    });
+
+    let mut edges = HashSet::new();
+    for line in 0..=TILES {
+        for tile in 0..TILES {
+            for dir in [Point::EAST, Point::SOUTH] {
+                let across = Point::new(dir.y, dir.x) * (line * 9) as isize;
+                let start = across + dir * (tile * 9) as isize;
+                loop {
+                    for step in 1..9 {
+                        pixels[start + dir * step] = if rng.gen() { '#' } else { '.' };
+                    }
+                    let edge = (0..10)
+                        .map(|step| pixels[start + dir * step])
+                        .collect::<String>();
+                    let reversed = edge.chars().rev().collect::<String>();
+                    if edge != reversed && !edges.contains(&edge) && !edges.contains(&reversed) {
+                        edges.insert(edge);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut ids = (1000..10_000).collect::<Vec<usize>>();
+    ids.shuffle(rng);
+    let mut tiles = (0..TILES * TILES)
+        .map(|idx| {
+            let origin = Point::new((idx % TILES * 9) as isize, (idx / TILES * 9) as isize);
+            let orientations = pixels.sub_grid(origin, 10, 10).orientations();
+            Tile {
+                id: ids[idx],
+                image: orientations[rng.gen_range(0..8)].clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let corners = [0, TILES - 1, TILES * (TILES - 1), TILES * TILES - 1]
+        .iter()
+        .map(|idx| tiles[*idx].id as u64)
+        .product::<u64>();
+    tiles.shuffle(rng);
+
+    let tiles = tiles
+        .iter()
+        .map(|tile| format!("Tile {}:\n{}", tile.id, tile))
+        .collect::<Vec<_>>();
+    let roughness = image.iter().filter(|(_, ch)| **ch == '#').count();
+
+    Generated::new(tiles.join("\n"))
+        .with_answer(1, corners)
+        .with_answer(2, roughness - monsters * SEA_MONSTER.len())
+}
+
+#[cfg(test)]
+mod day20_test {
+    use std::str::FromStr;
+
+    use aoc_core::{visual::Rgb, Error, Solution};
+    use proptest::prelude::*;
+
+    use crate::{join_tiles, Day20, Tile};
+
+    const INPUT: &str = include_str!("../../inputs/day_20.txt");
+    const EXAMPLE: &str = include_str!("../../examples/day_20/example_1.txt");
+
+    const TEST_TILE: &str = "Tile 1471:
+            .#...##.##
+            #...#.#..#
+            ##.......#
+            #..#.....#
+            #..##....#
+            .#.#..#...
+            ##.##.#..#
+            ...###..#.
+            ......##..
+            .##..##.#.";
+
+    fn pixels(tile: &Tile) -> String {
+        tile.to_string().replace('\n', "")
+    }
+
+    #[test]
+    fn test_tile_flip() {
+        let mut t = Tile::from_str(TEST_TILE).unwrap();
+        t.flip();
+        assert_eq!(
+            pixels(&t),
+            String::from(
+                "##.##...#.
+                #..#.#...#
+                #.......##
+                #.....#..#
+                #....##..#
+                ...#..#.#.
+                #..#.##.##
+                .#..###...
+                ..##......
+                .#.##..##."
+            )
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_tile_rotation() {
+        let mut t = Tile::from_str(TEST_TILE).unwrap();
+        t.rotate();
+        assert_eq!(
+            pixels(&t),
+            String::from(
+                "#####.#...
+                #......#.#
+                ........#.
+                ##...##.##
+                #......#.#
+                .#..#.##..
+                ...#####..
+                .........#
+                #.#..##..#
+                .####.#..."
+            )
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_tile_getters() {
+        let t = Tile::from_str(TEST_TILE).unwrap();
+        assert_eq!(t.top(), String::from(".#...##.##"));
+        assert_eq!(t.bottom(), String::from(".##..##.#."));
+        assert_eq!(t.right(), String::from("#####.#..."));
+        assert_eq!(t.left(), String::from(".####.#..."));
+    }
+
+    #[test]
+    fn test_trim_tile() {
+        let t = Tile::from_str(TEST_TILE).unwrap();
+        let trimmed = "Tile 1471:
+            ...#.#..
+            #.......
+            ..#.....
+            ..##....
+            #.#..#..
+            #.##.#..
+            ..###..#
+            .....##.
+        ";
+        assert_eq!(t.trim_tile().image, Tile::from_str(trimmed).unwrap().image);
+    }
+
+    #[test]
+    fn test_join_tile() {
+        let t = Tile::from_str(TEST_TILE).unwrap();
+        let mut flipped = t.clone();
+        flipped.flip();
+        let tiles = vec![t.clone(), t, flipped.clone(), flipped];
+        let joined = join_tiles(&tiles);
+
+        let expected = Tile::from_str(
+            "Tile 0:
+            .#...##.##.#...##.##
+            #...#.#..##...#.#..#
+            ##.......###.......#
+            #..#.....##..#.....#
+            #..##....##..##....#
+            .#.#..#....#.#..#...
+            ##.##.#..###.##.#..#
+            ...###..#....###..#.
+            ......##........##..
+            .##..##.#..##..##.#.
+
+            ##.##...#.##.##...#.
+            #..#.#...##..#.#...#
+            #.......###.......##
+            #.....#..##.....#..#
+            #....##..##....##..#
+            ...#..#.#....#..#.#.
+            #..#.##.###..#.##.##
+            .#..###....#..###...
+            ..##........##......
+            .#.##..##..#.##..##.
+            ",
+        )
+        .unwrap();
+        assert_eq!(joined.image, expected.image);
+    }
+
+    fn tiles() -> impl Strategy<Value = Tile> {
+        (2..12usize).prop_flat_map(|width| {
+            prop::collection::vec(
+                prop::string::string_regex(&format!("[.#]{{{}}}", width)).unwrap(),
+                width,
+            )
+            .prop_map(|rows| Tile::from_str(&format!("Tile 7:\n{}", rows.join("\n"))).unwrap())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_rotating_four_times_is_identity(tile in tiles()) {
+            let mut rotated = tile.clone();
+            for _ in 0..4 {
+                rotated.rotate();
+            }
+            prop_assert_eq!(rotated.image, tile.image);
+        }
+
+        #[test]
+        fn test_orientations_keep_edges(tile in tiles()) {
+            let mut flipped = tile.clone();
+            flipped.flip();
+            flipped.flip();
+            prop_assert_eq!(&flipped.image, &tile.image);
+
+            // Every orientation has the same set of borders, some of them reversed
+            let borders = |tile: &Tile| {
+                let mut borders = [tile.top(), tile.right(), tile.bottom(), tile.left()]
+                    .iter()
+                    .map(|border| border.clone().min(border.chars().rev().collect()))
+                    .collect::<Vec<_>>();
+                borders.sort();
+                borders
+            };
+            let expected = borders(&tile);
+            for orientation in tile.orientations() {
+                prop_assert_eq!(orientation.id, 7);
+                prop_assert_eq!(borders(&orientation), expected.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        let mut session = Day20::explore(&input).unwrap();
+        let mut execute = |line: &str| {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            session.execute(words[0], &words[1..])
+        };
+
+        assert!(execute("tiles")
+            .unwrap()
+            .starts_with("9 tiles: 1171, 1427,"));
+        assert_eq!(execute("tile 1427 0").unwrap().lines().count(), 10);
+        assert_eq!(
+            execute("tile 2311 4").unwrap().lines().last(),
+            Some("###..###..")
+        );
+        assert!(execute("tile 2311 8").is_err());
+        assert!(execute("edges 2311")
+            .unwrap()
+            .starts_with("top    ..##.#..#."));
+        assert_eq!(execute("neighbours 1427").unwrap().lines().count(), 4);
+        assert_eq!(execute("neighbours 1951").unwrap().lines().count(), 2);
+
+        let layout = execute("layout").unwrap();
+        let rows = layout.lines().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(&rows[1][5..9], "1427");
+        let corners = [&rows[0][..4], &rows[0][10..], &rows[2][..4], &rows[2][10..]];
+        let product = corners.iter().map(|id| id.parse::<usize>().unwrap());
+        assert_eq!(product.product::<usize>(), 20899048083289);
+
+        assert!(execute("monsters").unwrap().contains(" 2"));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day20::solve_part1(EXAMPLE).unwrap(), 20899048083289);
+        assert_eq!(Day20::solve_part1(INPUT).unwrap(), 17148689442341);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day20::solve_part2(EXAMPLE).unwrap(), 273);
+        assert_eq!(Day20::solve_part2(INPUT).unwrap(), 2009);
+    }
+
+    #[test]
+    fn test_no_tiles() {
+        for input in ["", "\n\n"] {
+            let missing = Error::missing(input, input, "tiles");
+            assert_eq!(Day20::solve_part1(input), Err(missing.clone()));
+            assert_eq!(Day20::solve_part2(input), Err(missing));
+        }
+    }
+
+    #[test]
+    fn test_visualise() {
+        let input = Day20::parse(EXAMPLE).unwrap();
+        let mut frames = Vec::new();
+        Day20::visualise(&input, &(), 1, &mut frames).unwrap();
+
+        // Nine tiles of ten by ten pixels, separated by a pixel
+        assert_eq!(frames.len(), 9);
+        let last = frames.last().unwrap();
+        assert_eq!((last.width(), last.height()), (33, 33));
+
+        frames.clear();
+        Day20::visualise(&input, &(), 2, &mut frames).unwrap();
+
+        // Two sea monsters of 15 pixels each
+        assert_eq!(frames.len(), 2);
+        let monsters = frames[1]
+            .pixels()
+            .iter()
+            .filter(|pixel| **pixel == Rgb::RED);
+        assert_eq!(monsters.count(), 30);
+    }
+}
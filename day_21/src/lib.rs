@@ -0,0 +1,313 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    vec,
+};
+
+use aoc_core::{
+    generate::{self, Generated, Rng, SliceRandom, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Allergen Assessment";
+
+    type Input<'a> = (Vec<Food>, Vec<AllergenRule>);
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let (foods, rules) = input;
+        let allergen_map = build_allergen_map(rules)?;
+
+        let unique_ingredients = foods
+            .iter()
+            .flat_map(|f| f.ingredients.iter())
+            .cloned()
+            .collect::<HashSet<String>>();
+
+        let allergen_free_ingredients = unique_ingredients
+            .iter()
+            .filter(|ingredient| allergen_map.values().find(|x| x.eq(ingredient)).is_none())
+            .collect::<Vec<_>>();
+
+        let occurences_of_allergen_free_ingredients = allergen_free_ingredients
+            .iter()
+            .map(|i| count_ingredient_occurences(foods, i))
+            .sum::<usize>();
+
+        Ok(occurences_of_allergen_free_ingredients)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let (_, rules) = input;
+        let allergen_map = build_allergen_map(rules)?;
+
+        let mut dangerous_ingredients = allergen_map.iter().collect::<Vec<_>>();
+        dangerous_ingredients.sort_by(|left, right| left.0.cmp(right.0));
+        let dangerous_ingredients = dangerous_ingredients
+            .iter()
+            .map(|(_, v)| (*v).clone())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Ok(dangerous_ingredients)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+fn build_allergen_map(rules: &[AllergenRule]) -> Result<HashMap<String, String>> {
+    let mut occurences: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut allergen_map: HashMap<String, String> = HashMap::new();
+
+    for (allergen, ingredients) in rules {
+        match occurences.get(allergen) {
+            Some(e) => {
+                let merged = e.intersection(ingredients).cloned().collect::<HashSet<_>>();
+                occurences.insert(allergen.to_string(), merged);
+            }
+            None => {
+                occurences.insert(allergen.to_string(), ingredients.clone());
+            }
+        }
+    }
+
+    loop {
+        trace::count("elimination rounds");
+        let undecided_allergens = occurences.len();
+
+        for (allergen, ingredients) in occurences.iter_mut() {
+            let undecided_ingredients = ingredients
+                .iter()
+                .filter(|i| allergen_map.values().find(|x| x.eq(i)).is_none())
+                .cloned()
+                .collect::<HashSet<_>>();
+
+            // Every ingredient which may contain the allergen already contains another one
+            if undecided_ingredients.is_empty() {
+                return Err(Error::NoSolution);
+            }
+            if undecided_ingredients.len() == 1 {
+                allergen_map.insert(
+                    allergen.clone(),
+                    undecided_ingredients.into_iter().next().unwrap(),
+                );
+                ingredients.clear();
+            } else {
+                *ingredients = undecided_ingredients;
+            }
+        }
+
+        occurences.retain(|_, v| !v.is_empty());
+
+        if occurences.is_empty() {
+            break;
+        }
+
+        // No allergen could be narrowed down to a single ingredient
+        if occurences.len() == undecided_allergens {
+            return Err(Error::NoSolution);
+        }
+    }
+
+    Ok(allergen_map)
+}
+
+fn count_ingredient_occurences(foods: &[Food], i: &str) -> usize {
+    foods
+        .iter()
+        .flat_map(|f| f.ingredients.iter())
+        .filter(|ing| ing.eq(&i))
+        .count()
+}
+
+/// An allergen together with the ingredients of a food containing it
+pub type AllergenRule = (String, HashSet<String>);
+
+#[derive(Debug)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Food>, Vec<AllergenRule>)> {
+    let foods = normalize::lines(input)
+        .map(|line| parse_food(input, line))
+        .collect::<Result<Vec<Food>>>()?;
+
+    let mut rules = vec![];
+
+    for f in &foods {
+        for a in &f.allergens {
+            let mut set = HashSet::new();
+            f.ingredients.iter().for_each(|i| {
+                set.insert(i.to_string());
+            });
+            rules.push((a.clone(), set));
+        }
+    }
+
+    Ok((foods, rules))
+}
+
+fn parse_food(input: &str, line: &str) -> Result<Food> {
+    let (ingredients, allergens) = line
+        .split_once(" (contains ")
+        .and_then(|(ingredients, allergens)| Some((ingredients, allergens.strip_suffix(')')?)))
+        .ok_or_else(|| {
+            Error::parse_at(
+                input,
+                line,
+                "expected \"<ingredients> (contains <allergens>)\"",
+            )
+        })?;
+
+    Ok(Food {
+        ingredients: ingredients
+            .split_whitespace()
+            .map(|x| x.to_string())
+            .collect(),
+        allergens: allergens.split(", ").map(|x| x.to_string()).collect(),
+    })
+}
+
+/// Generates a list of foods where each of eight allergens is found in one of 200 ingredients.
+///
+/// Lists where the allergens cannot be narrowed down to exactly these ingredients are discarded.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    const ALLERGENS: [&str; 9] = [
+        "dairy",
+        "eggs",
+        "fish",
+        "nuts",
+        "peanuts",
+        "sesame",
+        "shellfish",
+        "soy",
+        "wheat",
+    ];
+
+    generate::retry(rng, |rng| {
+        // Ordered, so that the same seed always leads to the same input
+        let mut ingredients = BTreeSet::new();
+        while ingredients.len() < 200 {
+            let len = rng.gen_range(4..=8);
+            ingredients.insert(
+                (0..len)
+                    .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                    .collect::<String>(),
+            );
+        }
+        let mut ingredients = ingredients.into_iter().collect::<Vec<_>>();
+        ingredients.shuffle(rng);
+
+        // The first eight ingredients contain the allergens
+        let allergens = ALLERGENS
+            .choose_multiple(rng, 8)
+            .copied()
+            .collect::<Vec<_>>();
+        let mut lines = Vec::new();
+        let mut rules = Vec::new();
+        let mut safe_occurences = 0;
+
+        for _ in 0..rng.gen_range(35..=45) {
+            let amount = rng.gen_range(40..=90);
+            let mut food = ingredients.choose_multiple(rng, amount).collect::<Vec<_>>();
+            let contained = rng.gen_range(1..=3);
+            let listed = allergens
+                .iter()
+                .enumerate()
+                .collect::<Vec<_>>()
+                .choose_multiple(rng, contained)
+                .copied()
+                .collect::<Vec<_>>();
+            for (idx, _) in &listed {
+                if !food.contains(&&ingredients[*idx]) {
+                    food.push(&ingredients[*idx]);
+                }
+            }
+            food.shuffle(rng);
+
+            let food = food.into_iter().cloned().collect::<Vec<_>>();
+            safe_occurences += food
+                .iter()
+                .filter(|ingredient| !ingredients[..8].contains(ingredient))
+                .count();
+            for (_, allergen) in &listed {
+                rules.push((allergen.to_string(), food.iter().cloned().collect()));
+            }
+            let listed = listed
+                .iter()
+                .map(|(_, allergen)| **allergen)
+                .collect::<Vec<_>>();
+            lines.push(format!(
+                "{} (contains {})",
+                food.join(" "),
+                listed.join(", ")
+            ));
+        }
+
+        let expected = allergens
+            .iter()
+            .zip(&ingredients)
+            .map(|(allergen, ingredient)| (allergen.to_string(), ingredient.clone()))
+            .collect::<HashMap<_, _>>();
+        if build_allergen_map(&rules).ok()? != expected {
+            return None;
+        }
+
+        let mut dangerous = allergens.iter().zip(&ingredients).collect::<Vec<_>>();
+        dangerous.sort_unstable();
+        let dangerous = dangerous
+            .iter()
+            .map(|(_, ingredient)| ingredient.as_str())
+            .collect::<Vec<_>>();
+
+        Some(
+            Generated::new(lines.join("\n"))
+                .with_answer(1, safe_occurences)
+                .with_answer(2, dangerous.join(",")),
+        )
+    })
+}
+
+#[cfg(test)]
+mod day21_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::Day21;
+
+    const INPUT: &str = include_str!("../../inputs/day_21.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day21::solve_part1(INPUT).unwrap(), 2230);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day21::solve_part2(INPUT).unwrap(),
+            "qqskn,ccvnlbp,tcm,jnqcd,qjqb,xjqd,xhzr,cjxv"
+        );
+    }
+
+    #[test]
+    fn test_no_candidates() {
+        // No ingredient is in both foods containing x, and a cannot contain both x and y
+        for input in ["a b (contains x)\nc (contains x)", "a (contains x, y)"] {
+            assert_eq!(Day21::solve_part1(input), Err(Error::NoSolution));
+            assert_eq!(Day21::solve_part2(input), Err(Error::NoSolution));
+        }
+    }
+}
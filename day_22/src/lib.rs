@@ -0,0 +1,224 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{
+    error,
+    generate::{Generated, SliceRandom, StdRng},
+    normalize, trace, Error, Result, Solution,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Crab Combat";
+
+    type Input<'a> = (Deck, Deck);
+    type Params = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        let (mut p1, mut p2) = input.clone();
+        let mut game_states = HashSet::new();
+
+        while !p1.is_empty() && !p2.is_empty() {
+            trace::count("rounds");
+            // Without the rule of recursive combat, a game repeating itself never ends
+            if !game_states.insert((p1.clone(), p2.clone())) {
+                return Err(Error::NoSolution);
+            }
+            let p1v = p1.pop_front().unwrap();
+            let p2v = p2.pop_front().unwrap();
+
+            if p1v > p2v {
+                p1.push_back(p1v);
+                p1.push_back(p2v);
+            }
+            if p2v > p1v {
+                p2.push_back(p2v);
+                p2.push_back(p1v);
+            }
+        }
+
+        let winner = match p1.is_empty() {
+            true => p2,
+            false => p1,
+        };
+
+        Ok(calculate_score(&winner))
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        let (p1, p2) = input.clone();
+        let (_, deck) = play_game(p1, p2);
+        Ok(calculate_score(&deck))
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+pub type Deck = VecDeque<usize>;
+
+fn play_game(mut p1: Deck, mut p2: Deck) -> (bool, Deck) {
+    trace::count("games");
+    let mut game_states = HashSet::new();
+
+    while !p1.is_empty() && !p2.is_empty() {
+        trace::count("rounds");
+        let new_state = (p1.clone(), p2.clone());
+        if !game_states.insert(new_state) {
+            return (true, p1);
+        }
+
+        let p1v = p1.pop_front().unwrap();
+        let p2v = p2.pop_front().unwrap();
+
+        let has_player_one_won = {
+            if p1v <= p1.len() && p2v <= p2.len() {
+                let new_p1 = p1.iter().take(p1v).cloned().collect();
+                let new_p2 = p2.iter().take(p2v).cloned().collect();
+                let (winner, _) = play_game(new_p1, new_p2);
+                winner
+            } else {
+                p1v > p2v
+            }
+        };
+
+        if has_player_one_won {
+            p1.push_back(p1v);
+            p1.push_back(p2v);
+        } else {
+            p2.push_back(p2v);
+            p2.push_back(p1v);
+        }
+    }
+
+    if p1.is_empty() {
+        (false, p2)
+    } else {
+        (true, p1)
+    }
+}
+
+fn calculate_score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, value)| (idx + 1) * value)
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<(Deck, Deck)> {
+    let mut blocks = normalize::blocks(input);
+    let mut dealt = HashSet::new();
+    let player_one = parse_deck(input, blocks.next(), "Player 1:", &mut dealt)?;
+    let player_two = parse_deck(input, blocks.next(), "Player 2:", &mut dealt)?;
+
+    if let Some(block) = blocks.next() {
+        return Err(Error::parse_at(input, block, "expected only two decks"));
+    }
+
+    Ok((player_one, player_two))
+}
+
+/// Parses the block of a player, their `header` followed by their cards, none of which may have
+/// been `dealt` before, as a round between two equal cards would have no winner
+fn parse_deck(
+    input: &str,
+    block: Option<&str>,
+    header: &str,
+    dealt: &mut HashSet<usize>,
+) -> Result<Deck> {
+    let mut lines = block.into_iter().flat_map(normalize::lines);
+
+    match lines.next() {
+        Some(line) if line == header => {}
+        Some(line) => {
+            return Err(Error::parse_at(
+                input,
+                line,
+                format!("expected {:?}", header),
+            ))
+        }
+        None => return Err(Error::missing(input, input, header)),
+    }
+
+    lines
+        .map(|card| {
+            let value = error::parse(input, card)?;
+            if !dealt.insert(value) {
+                return Err(Error::parse_at(
+                    input,
+                    card,
+                    format!("card {} is dealt twice", value),
+                ));
+            }
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Generates two decks dealt from the 50 shuffled cards like in the puzzle. The answers are only
+/// known by playing, so none are given.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let mut cards = (1..=50)
+        .map(|card: usize| card.to_string())
+        .collect::<Vec<_>>();
+    cards.shuffle(rng);
+    let (player_one, player_two) = cards.split_at(25);
+
+    Generated::new(format!(
+        "Player 1:\n{}\n\nPlayer 2:\n{}",
+        player_one.join("\n"),
+        player_two.join("\n")
+    ))
+}
+
+#[cfg(test)]
+mod day22_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::Day22;
+
+    const INPUT: &str = include_str!("../../inputs/day_22.txt");
+    const EXAMPLE: &str = include_str!("../../examples/day_22/example_1.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day22::solve_part1(EXAMPLE).unwrap(), 306);
+        assert_eq!(Day22::solve_part1(INPUT).unwrap(), 32677);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day22::solve_part2(EXAMPLE).unwrap(), 291);
+        assert_eq!(Day22::solve_part2(INPUT).unwrap(), 33661);
+    }
+
+    #[test]
+    fn test_endless_game() {
+        // The example of a game which would repeat itself forever without the recursive rules
+        let input = "Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14";
+        assert_eq!(Day22::solve_part1(input), Err(Error::NoSolution));
+        assert_eq!(Day22::solve_part2(input), Ok(105));
+    }
+
+    #[test]
+    fn test_cards_dealt_twice() {
+        let input = "Player 1:\n1\n3\n\nPlayer 2:\n2\n3";
+        assert_eq!(
+            Day22::parse(input),
+            Err(Error::parse_at(
+                input,
+                &input[input.len() - 1..],
+                "card 3 is dealt twice"
+            ))
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_core::{
+    generate::{Generated, SliceRandom, StdRng},
+    normalize, params, trace, Error, Result, Solution,
+};
+
+pub struct Day23;
+
+params! {
+    pub struct Params {
+        /// Number of moves in part 1
+        part1_moves: usize = 100,
+        /// Number of cups in part 2, the labels of the input are followed by the next ones
+        part2_cups: usize = 1_000_000,
+        /// Number of moves in part 2
+        part2_moves: usize = 10_000_000,
+    }
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Crab Cups";
+
+    type Input<'a> = Vec<usize>;
+    type Params = Params;
+    type Output1 = String;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        Ok(play(input, input.len(), params.part1_moves).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        if params.part2_cups < input.len() {
+            return Err(Error::invalid_parameter(format!(
+                "part 2 needs at least as many cups as there are labels, {}",
+                input.len()
+            )));
+        }
+        Ok(play(input, params.part2_cups, params.part2_moves).star_product())
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+/// Plays `rounds` moves of the game, with the cups extended by the following labels until there
+/// are `total` cups.
+///
+/// `cups` has to be a permutation of `1..=n` with at least five cups, as returned by
+/// [`parse_input`].
+pub fn play(cups: &[usize], total: usize, rounds: usize) -> Ring {
+    let mut ring = build_ring(cups, total);
+
+    trace::count_by("moves", rounds as u64);
+    for _ in 0..rounds {
+        ring.tick();
+    }
+
+    ring
+}
+
+#[derive(Debug)]
+pub struct Ring {
+    cups: HashMap<usize, usize>,
+    current_cup: usize,
+    max: usize,
+}
+
+impl Ring {
+    /// Product of the two cups clockwise of cup 1, which hide the stars
+    pub fn star_product(&self) -> u64 {
+        let first = self.cups[&1];
+        let second = self.cups[&first];
+        first as u64 * second as u64
+    }
+
+    fn tick(&mut self) {
+        let three_cups = self.take_three_cups();
+        let mut destination_cup = self.current_cup - 1;
+
+        loop {
+            if destination_cup == 0 {
+                destination_cup = self.max;
+            }
+            if three_cups.contains(&destination_cup) {
+                destination_cup =
+                    (destination_cup - 1 + self.cups.len() + 3) % (self.cups.len() + 3);
+            } else {
+                break;
+            }
+        }
+
+        let [a, b, c] = three_cups;
+
+        let tail = self.cups[&destination_cup];
+        self.cups.insert(destination_cup, a);
+        self.cups.insert(a, b);
+        self.cups.insert(b, c);
+        self.cups.insert(c, tail);
+
+        self.inc_cup();
+    }
+
+    fn inc_cup(&mut self) -> usize {
+        self.current_cup = self.cups[&self.current_cup];
+        self.current_cup
+    }
+
+    fn take_three_cups(&mut self) -> [usize; 3] {
+        let first = self.cups[&self.current_cup];
+        let second = self.cups[&first];
+        let third = self.cups[&second];
+        let next = self.cups[&third];
+
+        self.cups.insert(self.current_cup, next);
+        self.cups.remove(&first);
+        self.cups.remove(&second);
+        self.cups.remove(&third);
+
+        [first, second, third]
+    }
+}
+
+impl From<Ring> for String {
+    fn from(val: Ring) -> Self {
+        let mut ptr = 1;
+        let mut ring = vec![];
+        while ring.len() < val.cups.len() - 1 {
+            let v = val.cups[&ptr];
+            ring.push(v);
+            ptr = v;
+        }
+        ring.iter().map(|n| n.to_string()).collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    let labels = normalize::trim(input);
+    let cups = labels
+        .char_indices()
+        .map(|(idx, ch)| {
+            ch.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| Error::parse_at(input, &labels[idx..], "expected a digit"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Each move takes three cups and needs a destination cup besides the current one
+    if cups.len() < 5 {
+        return Err(Error::parse_at(input, labels, "expected at least 5 cups"));
+    }
+    for label in 1..=cups.len() {
+        if !cups.contains(&label) {
+            return Err(Error::parse_at(
+                input,
+                labels,
+                format!("expected the labels 1 to {}, missing {}", cups.len(), label),
+            ));
+        }
+    }
+
+    Ok(cups)
+}
+
+fn build_ring(cups: &[usize], total: usize) -> Ring {
+    let mut digits = cups.iter().copied().collect::<VecDeque<usize>>();
+
+    let current_cup = *digits.iter().next().unwrap();
+
+    for i in cups.len() + 1..=total {
+        digits.push_back(i);
+    }
+
+    let max = *digits.iter().max().unwrap();
+
+    let it1 = digits.clone().into_iter();
+    let last = digits.pop_back().unwrap();
+    digits.push_front(last);
+
+    Ring {
+        current_cup,
+        max,
+        cups: digits.into_iter().zip(it1).collect(),
+    }
+}
+
+/// Generates a random arrangement of the cups 1 to 9.
+///
+/// The 100 moves of part 1 are replayed on a plain list, keeping the current cup in front, while
+/// part 2 would take as long to compute as to solve.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let mut cups = (1..=9).collect::<Vec<usize>>();
+    cups.shuffle(rng);
+    let labels = cups.iter().map(usize::to_string).collect::<String>();
+
+    for _ in 0..100 {
+        let picked = cups.drain(1..4).collect::<Vec<_>>();
+        let mut destination = cups[0];
+        loop {
+            destination = if destination == 1 { 9 } else { destination - 1 };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let idx = cups.iter().position(|cup| *cup == destination).unwrap();
+        cups.splice(idx + 1..idx + 1, picked);
+        cups.rotate_left(1);
+    }
+
+    let one = cups.iter().position(|cup| *cup == 1).unwrap();
+    cups.rotate_left(one);
+    let order = cups[1..].iter().map(usize::to_string).collect::<String>();
+
+    Generated::new(labels).with_answer(1, order)
+}
+
+#[cfg(test)]
+mod day23_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{parse_input, play, Day23, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_23.txt");
+
+    #[test]
+    fn test_part_1() {
+        let cups = parse_input("389125467").unwrap();
+        assert_eq!(&String::from(play(&cups, 9, 10)), "92658374");
+        assert_eq!(&String::from(play(&cups, 9, 100)), "67384529");
+        assert_eq!(&Day23::solve_part1(INPUT).unwrap(), "47598263");
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day23::solve_part2("389125467").unwrap(), 149245887792);
+        assert_eq!(Day23::solve_part2(INPUT).unwrap(), 248009574232);
+    }
+
+    #[test]
+    fn test_moves() {
+        let params = Params {
+            part1_moves: 10,
+            part2_cups: 9,
+            part2_moves: 10,
+        };
+        assert_eq!(
+            &Day23::solve_part1_with("389125467", &params).unwrap(),
+            "92658374"
+        );
+        assert_eq!(
+            Day23::solve_part2_with("389125467", &params).unwrap(),
+            9 * 2
+        );
+
+        // The labels of the input cannot be dropped
+        let params = Params {
+            part2_cups: 8,
+            ..params
+        };
+        assert_eq!(
+            Day23::solve_part2_with("389125467", &params),
+            Err(Error::invalid_parameter(
+                "part 2 needs at least as many cups as there are labels, 9"
+            ))
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::io::BufRead;
+
+use aoc_core::{
+    generate::{Generated, Rng, SliceRandom, StdRng},
+    normalize, params, stream, trace,
+    visual::{Frame, Rgb, Sink},
+    Error, Result, Solution,
+};
+use aoc_grid::{Hex, SparseGrid};
+
+pub struct Day24;
+
+params! {
+    pub struct Params {
+        /// Number of days the tiles flip for in part 2
+        days: usize = 100,
+    }
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Lobby Layout";
+
+    type Input<'a> = SparseGrid<Hex>;
+    type Params = Params;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        build_initial_floor(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output1> {
+        Ok(input.len())
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output2> {
+        Ok(live(input, params.days, |_| {}).len())
+    }
+
+    fn stream_part1(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output1> {
+        Self::part1(&stream_initial_floor(reader)?, params)
+    }
+
+    fn stream_part2(reader: &mut dyn BufRead, params: &Self::Params) -> Result<Self::Output2> {
+        Self::part2(&stream_initial_floor(reader)?, params)
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+
+    /// Shows the initial floor, and for part 2 how it changes every day
+    fn visualise(
+        input: &Self::Input<'_>,
+        params: &Self::Params,
+        part: u8,
+        sink: &mut dyn Sink,
+    ) -> Result<()> {
+        let mut floors = vec![input.clone()];
+        if part == 2 {
+            live(input, params.days, |floor| floors.push(floor.clone()));
+        }
+
+        let tiles = floors.iter().flat_map(|floor| floor.points());
+        let (min, max) = tiles.fold(
+            ((isize::MAX, isize::MAX), (isize::MIN, isize::MIN)),
+            |(min, max), tile| {
+                let (x, y) = pixel(tile);
+                ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+            },
+        );
+
+        for floor in &floors {
+            let mut frame = Frame::new(
+                (max.0 - min.0 + 2).max(0) as usize,
+                (max.1 - min.1 + 1).max(0) as usize,
+                Rgb::WHITE,
+            );
+            for tile in floor.points() {
+                let (x, y) = pixel(tile);
+                frame.set(x - min.0, y - min.1, Rgb::BLACK);
+                frame.set(x - min.0 + 1, y - min.1, Rgb::BLACK);
+            }
+            sink.frame(frame);
+        }
+
+        Ok(())
+    }
+}
+
+/// Lets the tiles flip for the given number of `days` and returns the black tiles afterwards,
+/// calling `observe` with them after every day
+fn live(
+    floor: &SparseGrid<Hex>,
+    days: usize,
+    mut observe: impl FnMut(&SparseGrid<Hex>),
+) -> SparseGrid<Hex> {
+    let mut floor = floor.clone();
+
+    for _ in 0..days {
+        let _span = trace::span("day");
+        floor = floor.step(Hex::neighbours, |is_black, black_neighbours| {
+            black_neighbours == 2 || (is_black && black_neighbours == 1)
+        });
+        observe(&floor);
+    }
+
+    floor
+}
+
+/// Position of the left of the two pixels drawing a tile, rows of tiles being offset by half a
+/// tile from each other
+fn pixel(tile: Hex) -> (isize, isize) {
+    (2 * tile.q + tile.r, tile.r)
+}
+
+/// Flips the tile at the end of each line, returning the black tiles
+fn build_initial_floor(input: &str) -> Result<SparseGrid<Hex>> {
+    let mut floor = SparseGrid::new();
+
+    for line in normalize::lines(input) {
+        floor.toggle(parse_tile(input, line)?);
+    }
+
+    Ok(floor)
+}
+
+/// Flips the tiles while reading the lines, only the black tiles are kept
+fn stream_initial_floor(reader: &mut dyn BufRead) -> Result<SparseGrid<Hex>> {
+    let mut floor = SparseGrid::new();
+
+    for line in stream::lines(reader) {
+        floor.toggle(line?.parse(parse_tile)?);
+    }
+
+    Ok(floor)
+}
+
+/// Parses the moves leading to a tile, `line` has to be a slice of `input` for error reporting
+fn parse_tile(input: &str, line: &str) -> Result<Hex> {
+    let mut slice = line;
+    let mut tile = Hex::ORIGIN;
+
+    while let Some(first) = slice.chars().next() {
+        let second = slice.chars().nth(1);
+
+        let (direction, length) = match (first, second) {
+            ('n', Some('w')) => (Hex::NORTH_WEST, 2),
+            ('n', Some('e')) => (Hex::NORTH_EAST, 2),
+            ('s', Some('e')) => (Hex::SOUTH_EAST, 2),
+            ('s', Some('w')) => (Hex::SOUTH_WEST, 2),
+            ('e', _) => (Hex::EAST, 1),
+            ('w', _) => (Hex::WEST, 1),
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    slice,
+                    "expected one of e, se, sw, w, nw or ne",
+                ))
+            }
+        };
+
+        tile += direction;
+        slice = &slice[length..];
+    }
+
+    Ok(tile)
+}
+
+/// Generates paths to tiles within ten steps of the reference tile, some of which are flipped
+/// more than once.
+///
+/// Every path walks the shortest way to its tile with a few detours that cancel out, in random
+/// order. The answer of part 2 is only known by living through the 100 days, so it is not given.
+fn generate_input(rng: &mut StdRng) -> Generated {
+    // In the order of `Hex::DIRECTIONS`, so opposite directions are three apart
+    const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+    let tiles = (0..rng.gen_range(300..=350))
+        .map(|_| Hex::new(rng.gen_range(-10..=10), rng.gen_range(-10..=10)))
+        .collect::<Vec<_>>();
+    let mut floor = SparseGrid::new();
+    let mut lines = Vec::new();
+
+    for _ in 0..rng.gen_range(400..=450) {
+        let tile = *tiles.choose(rng).unwrap();
+        floor.toggle(tile);
+
+        let east_west = if tile.q > 0 { 0 } else { 3 };
+        let south_north = if tile.r > 0 { 1 } else { 4 };
+        let mut moves = vec![east_west; tile.q.unsigned_abs()];
+        moves.extend(vec![south_north; tile.r.unsigned_abs()]);
+        // The reference tile itself still needs a detour, as an empty line is no path at all
+        let detours = if moves.is_empty() { 1..=4 } else { 0..=4 };
+        for _ in 0..rng.gen_range(detours) {
+            let dir = rng.gen_range(0..6);
+            moves.extend([dir, (dir + 3) % 6]);
+        }
+        moves.shuffle(rng);
+
+        lines.push(moves.iter().map(|dir| DIRECTIONS[*dir]).collect::<String>());
+    }
+
+    Generated::new(lines.join("\n")).with_answer(1, floor.len())
+}
+
+#[cfg(test)]
+mod day24_test {
+    use aoc_core::{
+        visual::{Frame, Rgb},
+        Error, Solution,
+    };
+
+    use crate::{Day24, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_24.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day24::solve_part1(INPUT).unwrap(), 420);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day24::solve_part1("esew\nnwwswn\nee").unwrap_err(),
+            Error::parse(2, 6, "expected one of e, se, sw, w, nw or ne")
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day24::solve_part2(INPUT).unwrap(), 4206);
+    }
+
+    #[test]
+    fn test_stream() {
+        let params = Params::default();
+        assert_eq!(Day24::stream_part1(&mut INPUT.as_bytes(), &params), Ok(420));
+        assert_eq!(
+            Day24::stream_part2(&mut INPUT.as_bytes(), &params),
+            Ok(4206)
+        );
+        assert_eq!(
+            Day24::stream_part1(&mut "esew\nnwwswn\nee".as_bytes(), &params),
+            Err(Error::parse(2, 6, "expected one of e, se, sw, w, nw or ne"))
+        );
+    }
+
+    #[test]
+    fn test_visualise() {
+        let input = Day24::parse(INPUT).unwrap();
+        let mut frames = Vec::new();
+        Day24::visualise(&input, &Params::default(), 2, &mut frames).unwrap();
+
+        assert_eq!(frames.len(), 101);
+        let black = |frame: &Frame| {
+            let pixels = frame.pixels().iter().filter(|pixel| **pixel == Rgb::BLACK);
+            pixels.count() / 2
+        };
+        assert_eq!(black(&frames[0]), 420);
+        assert_eq!(black(&frames[100]), 4206);
+
+        frames.clear();
+        Day24::visualise(&input, &Params { days: 3 }, 2, &mut frames).unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(
+            black(&frames[3]),
+            Day24::solve_part2_with(INPUT, &Params { days: 3 }).unwrap()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashMap, convert::Infallible};
+
+use aoc_core::{
+    error,
+    generate::{Generated, Rng, StdRng},
+    normalize, params, trace, Error, Result, Solution,
+};
+
+pub struct Day25;
+
+params! {
+    pub struct Params {
+        /// Subject number the public keys are derived from
+        subject: u64 = 7,
+        /// Prime modulus of the handshake
+        modulus: u64 = 20201227,
+    }
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Combo Breaker";
+    const PARTS: u8 = 1;
+
+    /// Public keys of the card and the door
+    type Input<'a> = (u64, u64);
+    type Params = Params;
+    type Output1 = u64;
+    type Output2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut keys = normalize::trim(input)
+            .split_whitespace()
+            .map(|key| error::parse(input, key));
+        match (keys.next(), keys.next()) {
+            (Some(card), Some(door)) => Ok((card?, door?)),
+            (Some(_), None) => Err(Error::missing(input, input, "public key of the door")),
+            _ => Err(Error::missing(input, input, "public key of the card")),
+        }
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &Self::Params) -> Result<Self::Output1> {
+        let (pkey1, pkey2) = *input;
+        find_encryption_key(pkey1, pkey2, params)
+    }
+
+    /// Day 25 has no second puzzle
+    fn part2(_: &Self::Input<'_>, _: &Self::Params) -> Result<Self::Output2> {
+        Err(Error::invalid_parameter("day 25 has no part 2"))
+    }
+
+    fn generate(rng: &mut StdRng) -> Generated {
+        generate_input(rng)
+    }
+}
+
+fn mod_exp(g: u64, e: u64, modulo: u64) -> u64 {
+    trace::count_by("multiplications", e);
+    let g = g % modulo;
+    let mut c = 1;
+    for _ in 0..e {
+        c = c * g % modulo;
+    }
+    c
+}
+
+fn find_encryption_key(pkey1: u64, pkey2: u64, params: &Params) -> Result<u64> {
+    let Params { subject, modulus } = *params;
+    // Products of two numbers below the modulus have to fit into a `u64`
+    if !(3..=u32::MAX as u64).contains(&modulus) {
+        return Err(Error::invalid_parameter(format!(
+            "the modulus has to be between 3 and {}",
+            u32::MAX
+        )));
+    }
+    // No power of 0 but the first is 1, nor is 1 ever transformed into anything else
+    if subject % modulus == 0 {
+        return Err(Error::invalid_parameter(
+            "the subject number must not be a multiple of the modulus",
+        ));
+    }
+
+    let loop_size_door =
+        babystep_giantstep(subject % modulus, modulus, pkey2 % modulus).ok_or(Error::NoSolution)?;
+    Ok(mod_exp(pkey1, loop_size_door, modulus))
+}
+
+/// See https://en.wikipedia.org/wiki/Baby-step_giant-step for calculating discrete logarithms.
+/// Candidates are checked before being accepted, as the steps only find the logarithm for a prime
+/// modulus
+#[allow(clippy::many_single_char_names)]
+fn babystep_giantstep(g: u64, r#mod: u64, h: u64) -> Option<u64> {
+    let mut table = HashMap::new();
+
+    let m = (r#mod as f32).sqrt().ceil() as u64;
+
+    let mut e = 1;
+    for j in 0..m {
+        trace::count("baby steps");
+        table.insert(e, j);
+        e = e * g % r#mod;
+    }
+
+    let factor = mod_exp(g, r#mod - m - 1, r#mod);
+    e = h;
+
+    for i in 0..m {
+        trace::count("giant steps");
+        match table.get(&e) {
+            Some(v) if mod_exp(g, i * m + *v, r#mod) == h => return Some(i * m + *v),
+            _ => {
+                e = (e * factor) % r#mod;
+            }
+        }
+    }
+
+    None
+}
+
+/// Generates the public keys of a card and a door with random loop sizes in the range of the
+/// puzzle's, from which the encryption key follows directly
+fn generate_input(rng: &mut StdRng) -> Generated {
+    let card_loop = rng.gen_range(1_000_000..20_000_000);
+    let door_loop = rng.gen_range(1_000_000..20_000_000);
+    let card = mod_exp(7, card_loop, 20201227);
+    let door = mod_exp(7, door_loop, 20201227);
+
+    Generated::new(format!("{}\n{}", card, door)).with_answer(1, mod_exp(door, card_loop, 20201227))
+}
+
+#[cfg(test)]
+mod day25_test {
+    use aoc_core::{Error, Solution};
+
+    use crate::{Day25, Params};
+
+    const INPUT: &str = include_str!("../../inputs/day_25.txt");
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day25::solve_part1("5764801\n17807724").unwrap(), 14897079);
+        assert_eq!(Day25::solve_part1(INPUT).unwrap(), 181800);
+    }
+
+    #[test]
+    fn test_modulus() {
+        // 2 generates all residues modulo 11, the loop sizes are 3 and 4
+        let params = Params {
+            subject: 2,
+            modulus: 11,
+        };
+        assert_eq!(Day25::solve_part1_with("8\n5", &params).unwrap(), 4);
+
+        let params = Params {
+            modulus: 1,
+            ..Params::default()
+        };
+        assert!(Day25::solve_part1_with("8\n5", &params).is_err());
+
+        // Giant steps from 5 reach powers of 2 modulo 12, but 5 is none of them
+        let params = Params {
+            subject: 2,
+            modulus: 12,
+        };
+        assert_eq!(
+            Day25::solve_part1_with("8\n5", &params),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn test_subject() {
+        for subject in [0, 11, 22] {
+            let params = Params {
+                subject,
+                modulus: 11,
+            };
+            assert_eq!(
+                Day25::solve_part1_with("8\n5", &params),
+                Err(Error::invalid_parameter(
+                    "the subject number must not be a multiple of the modulus"
+                ))
+            );
+        }
+    }
+}
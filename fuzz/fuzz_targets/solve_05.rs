@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Solves both parts with the small parameters, which keep arbitrary inputs quick to solve
+fuzz_target!(|input: &str| {
+    let day = aoc_days::find(5).unwrap();
+    for part in day.parts() {
+        let _ = day.solve_with(part, input, &day.small_params());
+    }
+});